@@ -0,0 +1,61 @@
+//! Generates a test for every day, dataset and part under `data/`, see
+//! `tests/examples.rs`.
+
+use std::{env, fmt::Write, fs, path::Path};
+
+fn main() {
+  let data = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("data");
+  println!("cargo:rerun-if-changed={}", data.display());
+
+  let mut days = fs::read_dir(&data)
+    .map(|entries| {
+      entries
+        .filter_map(|entry| {
+          let name = entry.ok()?.file_name().into_string().ok()?;
+          let number = name.strip_prefix("day")?.parse::<u8>().ok()?;
+          return Some(number);
+        })
+        .collect::<Vec<_>>()
+    })
+    .unwrap_or_default();
+  days.sort_unstable();
+
+  let mut tests = String::new();
+  for day in days {
+    let mut datasets = fs::read_dir(data.join(format!("day{day}")))
+      .unwrap()
+      .filter_map(|entry| {
+        let name = entry.ok()?.file_name().into_string().ok()?;
+        return Some(name.strip_suffix(".txt")?.to_string());
+      })
+      .collect::<Vec<_>>();
+    datasets.sort_unstable();
+
+    for dataset in datasets {
+      let expected = data
+        .join(format!("day{day}"))
+        .join(format!("{dataset}.expected"));
+      let expected = fs::read_to_string(expected).unwrap_or_default();
+      let name = dataset.replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+
+      for (part, variant) in [(1, "One"), (2, "Two")] {
+        writeln!(tests, "#[test]").unwrap();
+        // Pending until `aoc record` knows the answer.
+        if !expected
+          .lines()
+          .any(|line| line == format!("--- part {part}"))
+        {
+          writeln!(tests, "#[ignore = \"no expected answer yet\"]").unwrap();
+        }
+        writeln!(
+          tests,
+          "fn day{day}_{name}_part{part}() {{\n  check({day}, {dataset:?}, Part::{variant});\n}}\n"
+        )
+        .unwrap();
+      }
+    }
+  }
+
+  let out = Path::new(&env::var("OUT_DIR").unwrap()).join("examples.rs");
+  fs::write(out, tests).unwrap();
+}
@@ -0,0 +1,131 @@
+use std::{
+  fmt::Display,
+  sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// What a phase allocated, only known with the `alloc-stats` feature:
+///
+/// ```sh
+/// cargo run --release --features alloc-stats -- run 17
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Memory {
+  pub allocations: usize,
+  /// Total, even if it was freed right away.
+  pub bytes: usize,
+  /// Most bytes alive at once, on top of what was alive before the phase.
+  pub peak: usize,
+}
+
+impl Display for Memory {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    return write!(
+      f,
+      "{} allocations, {} allocated, {} peak",
+      self.allocations,
+      Bytes(self.bytes),
+      Bytes(self.peak)
+    );
+  }
+}
+
+/// Formats a size with binary units, e.g. `1.5 MiB`.
+pub struct Bytes(pub usize);
+
+impl Display for Bytes {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if self.0 < 1024 {
+      return write!(f, "{} B", self.0);
+    }
+    let mut size = self.0 as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+      size /= 1024.0;
+      unit += 1;
+    }
+    return write!(f, "{size:.1} {}", UNITS[unit]);
+  }
+}
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Runs `f`, counting what it allocates when the `alloc-stats` feature is on.
+///
+/// The counters are global, so allocations from other threads running at the
+/// same time are counted too.
+pub(crate) fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Memory>) {
+  if !cfg!(feature = "alloc-stats") {
+    return (f(), None);
+  }
+
+  let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+  let bytes = BYTES.load(Ordering::Relaxed);
+  let live = LIVE.load(Ordering::Relaxed);
+  PEAK.store(live, Ordering::Relaxed);
+
+  let result = f();
+  let memory = Memory {
+    allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+    bytes: BYTES.load(Ordering::Relaxed) - bytes,
+    peak: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+  };
+  return (result, Some(memory));
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+  use std::alloc::{GlobalAlloc, Layout, System};
+  use std::sync::atomic::Ordering;
+
+  use super::{ALLOCATIONS, BYTES, LIVE, PEAK};
+
+  /// The system allocator, keeping count of everything that goes through it.
+  struct Counting;
+
+  #[global_allocator]
+  static GLOBAL: Counting = Counting;
+
+  fn allocated(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    BYTES.fetch_add(size, Ordering::Relaxed);
+    let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+  }
+
+  unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+      let ptr = System.alloc(layout);
+      if !ptr.is_null() {
+        allocated(layout.size());
+      }
+      return ptr;
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+      let ptr = System.alloc_zeroed(layout);
+      if !ptr.is_null() {
+        allocated(layout.size());
+      }
+      return ptr;
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+      System.dealloc(ptr, layout);
+      LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    /// Counted as a new allocation that frees the old one.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+      let new_ptr = System.realloc(ptr, layout, new_size);
+      if !new_ptr.is_null() {
+        allocated(new_size);
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+      }
+      return new_ptr;
+    }
+  }
+}
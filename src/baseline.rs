@@ -0,0 +1,140 @@
+use std::{fs, io, path::Path};
+
+use crate::{Benchmark, Duration};
+
+/// Median timings of previous benchmarks, one line per day, dataset and phase:
+///
+/// ```text
+/// day14  input  part 2  40123456
+/// ```
+///
+/// The last column is the median in nanoseconds, columns are separated by a
+/// tab (shown as spaces above).
+#[derive(Clone, Debug, Default)]
+pub struct Baseline {
+  entries: Vec<Entry>,
+}
+
+#[derive(Clone, Debug)]
+struct Entry {
+  day: String,
+  dataset: String,
+  phase: String,
+  median: u128,
+}
+
+/// How a phase compares against the baseline.
+pub struct Comparison {
+  pub phase: &'static str,
+  pub baseline: Duration,
+  pub current: Duration,
+  /// Positive when it got slower.
+  pub change: f64,
+  /// Got slower by more than the threshold.
+  pub regressed: bool,
+}
+
+impl Baseline {
+  /// Returns an empty baseline when the file doesn't exist.
+  pub fn read(path: &Path) -> io::Result<Self> {
+    let content = match fs::read_to_string(path) {
+      Ok(content) => content,
+      Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+      Err(e) => return Err(e),
+    };
+
+    let invalid = |i: usize| {
+      let message = format!(
+        "{}:{}: expected day, dataset, phase and nanoseconds",
+        path.display(),
+        i + 1
+      );
+      return io::Error::new(io::ErrorKind::InvalidData, message);
+    };
+
+    let entries = content
+      .lines()
+      .enumerate()
+      .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+      .map(
+        |(i, line)| match line.split('\t').collect::<Vec<_>>().as_slice() {
+          [day, dataset, phase, median] => Ok(Entry {
+            day: day.to_string(),
+            dataset: dataset.to_string(),
+            phase: phase.to_string(),
+            median: median.parse().map_err(|_| invalid(i))?,
+          }),
+          _ => Err(invalid(i)),
+        },
+      )
+      .collect::<io::Result<Vec<_>>>()?;
+
+    return Ok(Baseline { entries });
+  }
+
+  pub fn write(&self, path: &Path) -> io::Result<()> {
+    let content = self
+      .entries
+      .iter()
+      .map(|e| format!("{}\t{}\t{}\t{}\n", e.day, e.dataset, e.phase, e.median))
+      .collect::<String>();
+    return fs::write(
+      path,
+      format!("# day\tdataset\tphase\tmedian (ns)\n{content}"),
+    );
+  }
+
+  fn find(&self, day: &str, dataset: &str, phase: &str) -> Option<&Entry> {
+    return self
+      .entries
+      .iter()
+      .find(|e| e.day == day && e.dataset == dataset && e.phase == phase);
+  }
+
+  /// Replaces the timings of the phases in `benchmark`, keeping everything else.
+  pub fn update(&mut self, day: &str, benchmark: &Benchmark) {
+    let dataset = benchmark.source.name();
+    for (phase, stats) in benchmark.phases() {
+      let median = stats.median.0.as_nanos();
+      match self
+        .entries
+        .iter_mut()
+        .find(|e| e.day == day && e.dataset == dataset && e.phase == phase)
+      {
+        Some(entry) => entry.median = median,
+        None => self.entries.push(Entry {
+          day: day.to_string(),
+          dataset: dataset.clone(),
+          phase: phase.to_string(),
+          median,
+        }),
+      }
+    }
+  }
+
+  /// Compares the medians of `benchmark` with the baseline, `threshold` is a
+  /// percentage. Phases missing from the baseline are skipped.
+  pub fn compare(&self, day: &str, benchmark: &Benchmark, threshold: f64) -> Vec<Comparison> {
+    let dataset = benchmark.source.name();
+    return benchmark
+      .phases()
+      .into_iter()
+      .filter_map(|(phase, stats)| {
+        let baseline = self.find(day, &dataset, phase)?.median;
+        let current = stats.median.0.as_nanos();
+        let change = if baseline == 0 {
+          0.0
+        } else {
+          (current as f64 - baseline as f64) / baseline as f64 * 100.0
+        };
+        return Some(Comparison {
+          phase,
+          baseline: Duration(std::time::Duration::from_nanos(baseline as u64)),
+          current: stats.median,
+          change,
+          regressed: change > threshold,
+        });
+      })
+      .collect();
+  }
+}
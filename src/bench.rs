@@ -0,0 +1,84 @@
+use std::time::Instant;
+
+use crate::{Duration, Options, Source};
+
+/// Statistics over the timed iterations of a single phase.
+#[derive(Clone, Copy, Debug)]
+pub struct Stats {
+  pub min: Duration,
+  pub median: Duration,
+  pub mean: Duration,
+  /// Nearest-rank 95th percentile.
+  pub p95: Duration,
+  pub std_dev: Duration,
+}
+
+impl Stats {
+  pub fn new(mut samples: Vec<std::time::Duration>) -> Self {
+    assert!(!samples.is_empty(), "no samples to compute stats from");
+    samples.sort_unstable();
+
+    let n = samples.len();
+    let nanos = samples.iter().map(|d| d.as_nanos() as f64);
+    let mean = nanos.clone().sum::<f64>() / n as f64;
+    let variance = nanos.map(|v| (v - mean).powi(2)).sum::<f64>() / n as f64;
+    let median = if n.is_multiple_of(2) {
+      (samples[n / 2 - 1] + samples[n / 2]) / 2
+    } else {
+      samples[n / 2]
+    };
+    let p95 = samples[((n as f64 * 0.95).ceil() as usize).max(1) - 1];
+
+    let from_nanos = |v: f64| Duration(std::time::Duration::from_nanos(v.round() as u64));
+    return Stats {
+      min: Duration(samples[0]),
+      median: Duration(median),
+      mean: from_nanos(mean),
+      p95: Duration(p95),
+      std_dev: from_nanos(variance.sqrt()),
+    };
+  }
+}
+
+/// Benchmark of a day for a single source, see `aoc bench`.
+pub struct Benchmark {
+  pub source: Source,
+  pub read: Stats,
+  /// `None` when the part was not picked in `Options`.
+  pub part1: Option<Stats>,
+  pub part2: Option<Stats>,
+}
+
+impl Benchmark {
+  /// The phases that were benchmarked, named `read`, `part 1` and `part 2`.
+  pub fn phases(&self) -> Vec<(&'static str, Stats)> {
+    return [
+      ("read", Some(self.read)),
+      ("part 1", self.part1),
+      ("part 2", self.part2),
+    ]
+    .into_iter()
+    .filter_map(|(phase, stats)| Some((phase, stats?)))
+    .collect();
+  }
+}
+
+/// Calls `f` `options.warmup` times, then times it `options.iterations` times.
+///
+/// Dropping the result is not part of the timing. The last result is returned.
+pub(crate) fn sample<T>(options: &Options, f: &dyn Fn() -> T) -> (T, Stats) {
+  for _ in 0..options.warmup {
+    f();
+  }
+
+  let mut samples = Vec::with_capacity(options.iterations);
+  let mut last = None;
+  for _ in 0..options.iterations {
+    let start = Instant::now();
+    let result = f();
+    samples.push(start.elapsed());
+    last = Some(result);
+  }
+
+  return (last.unwrap(), Stats::new(samples));
+}
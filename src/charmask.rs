@@ -0,0 +1,283 @@
+use std::{
+  fmt::{self, Debug},
+  hash::Hash,
+  marker::PhantomData,
+  ops::{BitAnd, BitOr, BitXor, Sub},
+};
+
+/// Maps the characters of a `CharMask` to bits and back.
+///
+/// Indexes must be below `CharMask::CAPACITY`.
+pub trait Alphabet: Copy + Eq + Hash {
+  /// `None` when `c` isn't part of the alphabet.
+  fn index(c: char) -> Option<usize>;
+  fn char(index: usize) -> char;
+}
+
+/// `a-z` then `A-Z`, from 1 to 52 so that the index is the day3 priority.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Letters;
+
+impl Alphabet for Letters {
+  fn index(c: char) -> Option<usize> {
+    return match c {
+      'a'..='z' => Some((c as u8 - b'a') as usize + 1),
+      'A'..='Z' => Some((c as u8 - b'A') as usize + 27),
+      _ => None,
+    };
+  }
+
+  fn char(index: usize) -> char {
+    return match index {
+      1..=26 => (b'a' + index as u8 - 1) as char,
+      _ => (b'A' + index as u8 - 27) as char,
+    };
+  }
+}
+
+/// `0-9`, the index is the digit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Digits;
+
+impl Alphabet for Digits {
+  fn index(c: char) -> Option<usize> {
+    return c.to_digit(10).map(|d| d as usize);
+  }
+
+  fn char(index: usize) -> char {
+    return (b'0' + index as u8) as char;
+  }
+}
+
+/// The 128 ASCII characters, the index is the code.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Ascii;
+
+impl Alphabet for Ascii {
+  fn index(c: char) -> Option<usize> {
+    return c.is_ascii().then_some(c as usize);
+  }
+
+  fn char(index: usize) -> char {
+    return index as u8 as char;
+  }
+}
+
+const WORDS: usize = 2;
+
+/// A set of characters of an `Alphabet`, one bit each.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CharMask<A: Alphabet = Letters> {
+  words: [u64; WORDS],
+  alphabet: PhantomData<A>,
+}
+
+impl<A: Alphabet> CharMask<A> {
+  pub const CAPACITY: usize = WORDS * 64;
+
+  pub fn new() -> Self {
+    return Self::from_words([0; WORDS]);
+  }
+
+  fn from_words(words: [u64; WORDS]) -> Self {
+    return CharMask {
+      words,
+      alphabet: PhantomData,
+    };
+  }
+
+  fn zip(self, other: Self, f: impl Fn(u64, u64) -> u64) -> Self {
+    return Self::from_words(std::array::from_fn(|i| f(self.words[i], other.words[i])));
+  }
+
+  /// Whether `c` wasn't in the mask yet, or `Err(c)` when it's not part of
+  /// the alphabet.
+  pub fn insert(&mut self, c: char) -> Result<bool, char> {
+    let i = A::index(c).ok_or(c)?;
+    let (word, bit) = (i / 64, 1 << (i % 64));
+    let inserted = self.words[word] & bit == 0;
+    self.words[word] |= bit;
+    return Ok(inserted);
+  }
+
+  /// The characters of `iter`, or `Err(c)` with the first one that's not part
+  /// of the alphabet.
+  pub fn try_from_iter(iter: impl IntoIterator<Item = char>) -> Result<Self, char> {
+    let mut mask = Self::new();
+    for c in iter {
+      mask.insert(c)?;
+    }
+    return Ok(mask);
+  }
+
+  /// Whether `c` was in the mask.
+  pub fn remove(&mut self, c: char) -> bool {
+    let Some(i) = A::index(c) else {
+      return false;
+    };
+    let (word, bit) = (i / 64, 1 << (i % 64));
+    let removed = self.words[word] & bit != 0;
+    self.words[word] &= !bit;
+    return removed;
+  }
+
+  pub fn contains(&self, c: char) -> bool {
+    return A::index(c).is_some_and(|i| self.words[i / 64] & (1 << (i % 64)) != 0);
+  }
+
+  pub fn len(&self) -> usize {
+    return self.words.iter().map(|w| w.count_ones() as usize).sum();
+  }
+
+  pub fn is_empty(&self) -> bool {
+    return self.words.iter().all(|&w| w == 0);
+  }
+
+  pub fn union(self, other: Self) -> Self {
+    return self.zip(other, |a, b| a | b);
+  }
+
+  pub fn intersection(self, other: Self) -> Self {
+    return self.zip(other, |a, b| a & b);
+  }
+
+  pub fn difference(self, other: Self) -> Self {
+    return self.zip(other, |a, b| a & !b);
+  }
+
+  pub fn symmetric_difference(self, other: Self) -> Self {
+    return self.zip(other, |a, b| a ^ b);
+  }
+
+  pub fn is_subset(&self, other: &Self) -> bool {
+    return self.difference(*other).is_empty();
+  }
+
+  /// The character with the lowest index.
+  pub fn first(&self) -> Option<char> {
+    return self.iter().next();
+  }
+
+  /// In index order.
+  pub fn iter(&self) -> Iter<A> {
+    return Iter {
+      words: self.words,
+      word: 0,
+      alphabet: PhantomData,
+    };
+  }
+}
+
+impl<A: Alphabet> Default for CharMask<A> {
+  fn default() -> Self {
+    return Self::new();
+  }
+}
+
+impl<A: Alphabet> Debug for CharMask<A> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    return f.debug_set().entries(self.iter()).finish();
+  }
+}
+
+/// The characters of a string, or `Err(c)` with the first one that's not
+/// part of the alphabet.
+impl<A: Alphabet> TryFrom<&str> for CharMask<A> {
+  type Error = char;
+
+  fn try_from(s: &str) -> Result<Self, char> {
+    return Self::try_from_iter(s.chars());
+  }
+}
+
+/// # Panics
+///
+/// On any character outside of the alphabet. Only `collect` characters that
+/// are known to be part of it, otherwise use `CharMask::try_from_iter`.
+impl<A: Alphabet> FromIterator<char> for CharMask<A> {
+  fn from_iter<T: IntoIterator<Item = char>>(iter: T) -> Self {
+    return Self::try_from_iter(iter)
+      .unwrap_or_else(|c| panic!("`{c}` is not part of the alphabet"));
+  }
+}
+
+impl<A: Alphabet> IntoIterator for CharMask<A> {
+  type Item = char;
+  type IntoIter = Iter<A>;
+
+  fn into_iter(self) -> Iter<A> {
+    return self.iter();
+  }
+}
+
+impl<A: Alphabet> IntoIterator for &CharMask<A> {
+  type Item = char;
+  type IntoIter = Iter<A>;
+
+  fn into_iter(self) -> Iter<A> {
+    return self.iter();
+  }
+}
+
+/// Jumps from set bit to set bit rather than testing all of them.
+pub struct Iter<A> {
+  words: [u64; WORDS],
+  word: usize,
+  alphabet: PhantomData<A>,
+}
+
+impl<A: Alphabet> Iterator for Iter<A> {
+  type Item = char;
+
+  fn next(&mut self) -> Option<char> {
+    while self.word < WORDS {
+      let bits = self.words[self.word];
+      if bits != 0 {
+        // Clears the lowest set bit.
+        self.words[self.word] = bits & (bits - 1);
+        return Some(A::char(self.word * 64 + bits.trailing_zeros() as usize));
+      }
+      self.word += 1;
+    }
+    return None;
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let len = self.words.iter().map(|w| w.count_ones() as usize).sum();
+    return (len, Some(len));
+  }
+}
+
+impl<A: Alphabet> ExactSizeIterator for Iter<A> {}
+
+impl<A: Alphabet> BitOr for CharMask<A> {
+  type Output = Self;
+
+  fn bitor(self, other: Self) -> Self {
+    return self.union(other);
+  }
+}
+
+impl<A: Alphabet> BitAnd for CharMask<A> {
+  type Output = Self;
+
+  fn bitand(self, other: Self) -> Self {
+    return self.intersection(other);
+  }
+}
+
+impl<A: Alphabet> BitXor for CharMask<A> {
+  type Output = Self;
+
+  fn bitxor(self, other: Self) -> Self {
+    return self.symmetric_difference(other);
+  }
+}
+
+impl<A: Alphabet> Sub for CharMask<A> {
+  type Output = Self;
+
+  fn sub(self, other: Self) -> Self {
+    return self.difference(other);
+  }
+}
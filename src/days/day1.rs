@@ -0,0 +1,33 @@
+use crate::{self as aoc, Error, Lines, Result};
+
+pub fn solution() -> Box<dyn aoc::Solution> {
+  return aoc::solve(Day1);
+}
+
+struct Day1;
+
+impl aoc::Solver for Day1 {
+  type Input = Vec<i32>;
+  type Output1 = i32;
+  type Output2 = i32;
+
+  fn parse(&self, input: Lines) -> Result<Vec<i32>> {
+    let mut data = input
+      .groups()
+      .map(|elf| elf?.iter().map(|line| line.parse::<i32>(line)).sum())
+      .collect::<Result<Vec<i32>>>()?;
+    data.sort();
+    return Ok(data);
+  }
+
+  fn part1(&self, data: &Vec<i32>) -> Result<i32> {
+    return data
+      .last()
+      .map(|v| v.to_owned())
+      .ok_or_else(|| Error::new("there are no elves"));
+  }
+
+  fn part2(&self, data: &Vec<i32>) -> Result<i32> {
+    return Ok(data.iter().rev().take(3).sum());
+  }
+}
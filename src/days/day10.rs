@@ -1,28 +1,45 @@
 use std::collections::HashSet;
 
-use aoc;
-
-fn main() {
-  aoc::solve(
-    &|path| {
-      aoc::lines(path)
-        .iter()
-        .map(|line| {
-          let mut parts = line.splitn(2, " ");
-          return match parts.next().unwrap() {
-            "noop" => Operation::Noop,
-            "addx" => Operation::AddX(parts.next().unwrap().parse().unwrap()),
-            _ => panic!("impossible!"),
-          };
-        })
-        .collect::<Vec<Operation>>()
-    },
-    &p1,
-    &p2,
-  );
+use crate::{self as aoc, Lines, Result};
+
+pub fn solution() -> Box<dyn aoc::Solution> {
+  return aoc::solve(Day10);
 }
 
-fn p1(data: Vec<Operation>) -> i64 {
+struct Day10;
+
+impl aoc::Solver for Day10 {
+  type Input = Vec<Operation>;
+  type Output1 = i64;
+  type Output2 = String;
+
+  fn parse(&self, input: Lines) -> Result<Vec<Operation>> {
+    let lines = input.all()?;
+    return lines
+      .iter()
+      .map(|line| {
+        let mut parts = line.splitn(2, " ");
+        return match parts.next().unwrap() {
+          "noop" => Ok(Operation::Noop),
+          "addx" => Ok(Operation::AddX(
+            line.parse(line.expect(parts.next(), "a number")?)?,
+          )),
+          op => Err(line.error(op, format!("unknown instruction `{op}`"))),
+        };
+      })
+      .collect();
+  }
+
+  fn part1(&self, data: &Vec<Operation>) -> Result<i64> {
+    return Ok(p1(data));
+  }
+
+  fn part2(&self, data: &Vec<Operation>) -> Result<String> {
+    return Ok(p2(data));
+  }
+}
+
+fn p1(data: &[Operation]) -> i64 {
   let mut computer = Computer::new(data);
   let targets: HashSet<usize> = HashSet::from_iter((20..=220).step_by(40));
 
@@ -37,7 +54,7 @@ fn p1(data: Vec<Operation>) -> i64 {
   return sum;
 }
 
-fn p2(data: Vec<Operation>) -> () {
+fn p2(data: &[Operation]) -> String {
   let mut computer = Computer::new(data);
   let mut crt: HashSet<(usize, usize)> = HashSet::new();
   const ROWS: usize = 6;
@@ -52,7 +69,7 @@ fn p2(data: Vec<Operation>) -> () {
     }
   }
 
-  let result = (0..ROWS)
+  return (0..ROWS)
     .map(|i| {
       (0..COLS)
         .map(|j| if crt.contains(&(i, j)) { '█' } else { ' ' })
@@ -60,8 +77,6 @@ fn p2(data: Vec<Operation>) -> () {
     })
     .reduce(|acc, v| format!("{acc}\n{v}"))
     .unwrap();
-
-  println!("{result}");
 }
 
 #[derive(Clone)]
@@ -79,17 +94,17 @@ impl Operation {
   }
 }
 
-struct Computer {
+struct Computer<'a> {
   x: i64,
   pc: usize,
   clock: usize,
-  program: Vec<Operation>,
+  program: &'a [Operation],
   delay: usize,
   delayed_op: Option<Operation>,
 }
 
-impl Computer {
-  fn new(program: Vec<Operation>) -> Self {
+impl<'a> Computer<'a> {
+  fn new(program: &'a [Operation]) -> Self {
     return Computer {
       x: 1,
       pc: 0,
@@ -109,10 +124,9 @@ impl Computer {
     if self.pc >= self.program.len() {
       return;
     }
-    match self.delayed_op {
-      Some(Operation::AddX(v)) => self.x += v,
-      _ => (),
-    };
+    if let Some(Operation::AddX(v)) = self.delayed_op {
+      self.x += v;
+    }
 
     let op = &self.program[self.pc];
     self.delayed_op = Some(op.clone());
@@ -0,0 +1,178 @@
+use crate::{self as aoc, Error, Line, Lines, Result};
+
+pub fn solution() -> Box<dyn aoc::Solution> {
+  return aoc::solve(Day11);
+}
+
+struct Day11;
+
+impl aoc::Solver for Day11 {
+  type Input = (Vec<Monkey>, u64);
+  type Output1 = usize;
+  type Output2 = usize;
+
+  fn parse(&self, input: Lines) -> Result<(Vec<Monkey>, u64)> {
+    let groups = input.groups().collect::<Result<Vec<Vec<Line>>>>()?;
+    // The monkey business needs the two most active monkeys.
+    match &groups[..] {
+      [] => return Err(Error::new("expected at least 2 monkeys")),
+      [monkey] => return Err(monkey[0].error_at(1, "expected at least 2 monkeys")),
+      _ => (),
+    }
+    let data = groups
+      .iter()
+      .map(|lines| Monkey::from_lines(lines, groups.len()))
+      .collect::<Result<Vec<Monkey>>>()?;
+
+    // This is technically not the LCM, but all numbers happen to be prime.
+    let lcm = data.iter().map(|m| m.modulo).product();
+
+    return Ok((data, lcm));
+  }
+
+  fn part1(&self, (data, lcm): &(Vec<Monkey>, u64)) -> Result<usize> {
+    return Ok(simulate(data, 20, 3, *lcm));
+  }
+
+  fn part2(&self, (data, lcm): &(Vec<Monkey>, u64)) -> Result<usize> {
+    return Ok(simulate(data, 10_000, 1, *lcm));
+  }
+}
+
+#[derive(Clone, Debug)]
+struct Monkey {
+  items: Vec<u64>,
+  operator: Operator,
+  /// X = X op CHANGE.
+  /// When `change` is None it means X = X op X.
+  change: Option<u64>,
+  modulo: u64,
+  pass: usize,
+  fail: usize,
+  counter: usize,
+}
+
+impl Monkey {
+  fn from_lines(lines: &[Line], count: usize) -> Result<Self> {
+    let (line, [n]) = field(lines, 0, "Monkey {}:")?;
+    line.parse::<usize>(n)?;
+
+    let (line, [items]) = field(lines, 1, "  Starting items: {}")?;
+    let items = items
+      .split(", ")
+      .map(|n| line.parse(n))
+      .collect::<Result<_>>()?;
+
+    let (line, [operator, change]) = field(lines, 2, "  Operation: new = old {} {}")?;
+    let operator = match operator {
+      "*" => Operator::Multiply,
+      "+" => Operator::Add,
+      other => return Err(line.error(other, format!("invalid operator `{other}`"))),
+    };
+    let change = match change {
+      "old" => None,
+      n => Some(line.parse(n)?),
+    };
+
+    let (line, [modulo]) = field(lines, 3, "  Test: divisible by {}")?;
+    let modulo = line.parse(modulo)?;
+    if modulo == 0 {
+      return Err(line.error_at(line.len(), "can't be divisible by 0"));
+    }
+
+    let target = |i: usize, template: &str| -> Result<usize> {
+      let (line, [target]) = field(lines, i, template)?;
+      let target = line.parse(target)?;
+      if target >= count {
+        return Err(line.error_at(line.len(), format!("there are only {count} monkeys")));
+      }
+      return Ok(target);
+    };
+
+    let pass = target(4, "    If true: throw to monkey {}")?;
+    let fail = target(5, "    If false: throw to monkey {}")?;
+    if let Some(line) = lines.get(6) {
+      let extra = line.trim();
+      return Err(line.error(extra, format!("unexpected `{extra}`")));
+    }
+
+    return Ok(Self {
+      items,
+      operator,
+      change,
+      modulo,
+      pass,
+      fail,
+      counter: 0,
+    });
+  }
+
+  fn inspect_all(&mut self, worry_divisor: &u64, worry_mod: &u64) -> Vec<(u64, usize)> {
+    self.counter += self.items.len();
+    let updates = self
+      .items
+      .iter()
+      .map(|item| {
+        let worry_level = (self.operator.apply(item, self.change) / worry_divisor) % worry_mod;
+        let target = if worry_level.is_multiple_of(self.modulo) {
+          self.pass
+        } else {
+          self.fail
+        };
+        return (worry_level, target);
+      })
+      .collect();
+    self.items.clear();
+    return updates;
+  }
+}
+
+/// The fields of the `i`th line of a monkey, which should follow `template`.
+fn field<'a, const N: usize>(
+  lines: &'a [Line],
+  i: usize,
+  template: &str,
+) -> Result<(&'a Line, [&'a str; N])> {
+  let Some(line) = lines.get(i) else {
+    return Err(Error {
+      line: lines[lines.len() - 1].number + 1,
+      ..Error::new(format!("expected `{}`", template.replace("{}", "N").trim()))
+    });
+  };
+  return Ok((line, line.scan(template)?));
+}
+
+#[derive(Clone, Debug)]
+enum Operator {
+  Add,
+  Multiply,
+}
+
+impl Operator {
+  fn apply(&self, a: &u64, b: Option<u64>) -> u64 {
+    return match (self, b) {
+      (Operator::Add, None) => a * 2,
+      (Operator::Add, Some(b)) => a + b,
+      (Operator::Multiply, None) => a * a,
+      (Operator::Multiply, Some(b)) => a * b,
+    };
+  }
+}
+
+fn simulate(data: &[Monkey], rounds: usize, worry_divisor: u64, worry_mod: u64) -> usize {
+  // The monkeys keep passing items around.
+  let mut data = data.to_vec();
+
+  for _round in 0..rounds {
+    for i in 0..data.len() {
+      let updates = data[i].inspect_all(&worry_divisor, &worry_mod);
+      for (worry_level, target) in updates {
+        data[target].items.push(worry_level);
+      }
+    }
+  }
+
+  let mut counters: Vec<usize> = data.iter().map(|monkey| monkey.counter).collect();
+  counters.sort_unstable();
+  return counters[counters.len() - 1] * counters[counters.len() - 2];
+}
@@ -0,0 +1,72 @@
+use crate::{self as aoc, Error, Grid, Lines, Point2, Result};
+
+pub fn solution() -> Box<dyn aoc::Solution> {
+  return aoc::solve(Day12);
+}
+
+struct Day12;
+
+impl aoc::Solver for Day12 {
+  type Input = Grid<u8>;
+  type Output1 = usize;
+  type Output2 = usize;
+
+  fn parse(&self, input: Lines) -> Result<Grid<u8>> {
+    let lines = input.all()?;
+    let data = Grid::parse(&lines, |c| match c {
+      'a'..='z' | 'S' | 'E' => Some(c as u8),
+      _ => None,
+    })?;
+
+    for target in ['S', 'E'] {
+      if data.find(|&c| c == target as u8).is_none() {
+        return Err(Error::new(format!("there is no `{target}` square")));
+      }
+    }
+    return Ok(data);
+  }
+
+  fn part1(&self, data: &Grid<u8>) -> Result<usize> {
+    // Problem states S->E, but it's faster to go E to S.
+    return shortest_path_len(data, end(data), b'S')
+      .ok_or_else(|| Error::new("can't reach E from S"));
+  }
+
+  fn part2(&self, data: &Grid<u8>) -> Result<usize> {
+    // Problem states any(a)->E, but it's faster to go E to any(a).
+    return shortest_path_len(data, end(data), b'a')
+      .ok_or_else(|| Error::new("can't reach E from any `a`"));
+  }
+}
+
+type Point = Point2<usize>;
+
+/// Where `E` is, `parse` made sure there is one.
+fn end(data: &Grid<u8>) -> Point {
+  let (x, y) = data.find(|&c| c == b'E').unwrap();
+  return Point::new(x, y);
+}
+
+fn shortest_path_len(data: &Grid<u8>, start: Point, end: u8) -> Option<usize> {
+  let route = aoc::bfs([start], |&p| candidates(data, p), |&p| data[p] == end)?;
+  return Some(route.cost);
+}
+
+fn candidates(data: &Grid<u8>, current: Point) -> impl Iterator<Item = Point> + '_ {
+  let altitude = fix_altitude(data[current]);
+  return data
+    .neighbours4(current)
+    // The problem states that you can jump up +1 or down any number.
+    // However, navigating the maze (most mazes?) is faster backwards.
+    // So instead walking from the "end" to the "start" we check that we can
+    // only drop down -1 or up any number.
+    .filter(move |&p| fix_altitude(data[p]) >= altitude - 1);
+}
+
+fn fix_altitude(c: u8) -> u8 {
+  match c {
+    b'S' => b'a',
+    b'E' => b'z',
+    _ => c,
+  }
+}
@@ -0,0 +1,144 @@
+use std::cmp::Ordering;
+
+use crate::{self as aoc, Error, Lines, Result};
+
+pub fn solution() -> Box<dyn aoc::Solution> {
+  return aoc::solve(Day13);
+}
+
+struct Day13;
+
+impl aoc::Solver for Day13 {
+  type Input = Vec<Packet>;
+  type Output1 = usize;
+  type Output2 = usize;
+
+  fn parse(&self, input: Lines) -> Result<Vec<Packet>> {
+    let lines = input.all()?;
+    let data = lines
+      .iter()
+      .filter(|line| !line.is_empty())
+      .map(|line| Packet::from_str(line).map_err(|(i, message)| line.error_at(i + 1, message)))
+      .collect::<Result<Vec<Packet>>>()?;
+    if !data.len().is_multiple_of(2) {
+      return Err(Error::new(
+        "expected pairs of packets, the last one has no pair",
+      ));
+    }
+    return Ok(data);
+  }
+
+  fn part1(&self, data: &Vec<Packet>) -> Result<usize> {
+    return Ok(
+      data
+        .chunks(2)
+        .enumerate()
+        .filter(|(_, pair)| pair[0].cmp(&pair[1]).is_le())
+        .map(|(index, _)| index + 1)
+        .sum(),
+    );
+  }
+
+  /// Originally I just did `[...data, p1, p2].sort()`, but I don't really need
+  /// to sort. I can simply count how many are less than `p1` and `p2`.
+  fn part2(&self, data: &Vec<Packet>) -> Result<usize> {
+    let p1 = Packet::from_str("[[2]]").unwrap();
+    let p2 = Packet::from_str("[[6]]").unwrap();
+
+    let mut p1_index = data.iter().filter(|p| p.cmp(&&p1).is_lt()).count();
+    let mut p2_index = data.iter().filter(|p| p.cmp(&&p2).is_lt()).count();
+
+    // Add 1 because the problem says packets are one-indexed.
+    p1_index += 1;
+
+    // Add 2:
+    // - One becase the problem says packets are one-indexed.
+    // - Another because we never added `[[2]]` to the list.
+    p2_index += 2;
+
+    return Ok(p1_index * p2_index);
+  }
+}
+
+/// Where parsing a packet failed, as a 0-based character index, and why.
+type ParseResult<T> = std::result::Result<T, (usize, String)>;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Packet {
+  Number(u8),
+  List(Vec<Packet>),
+}
+
+impl Packet {
+  fn from_str(s: &str) -> ParseResult<Self> {
+    let line = s.chars().collect::<Vec<char>>();
+    let (packet, end) = Self::parse(&line, 0)?;
+    if let Some(c) = line.get(end) {
+      return Err((end, format!("unexpected `{c}` after the packet")));
+    }
+    return Ok(packet);
+  }
+
+  /// Parses the packet starting at `i`, returning it and the index after it.
+  fn parse(line: &[char], i: usize) -> ParseResult<(Self, usize)> {
+    return match line.get(i) {
+      Some('[') => Self::parse_list(line, i),
+      Some('0'..='9') => Self::parse_number(line, i),
+      Some(c) => Err((i, format!("unexpected `{c}`, expected `[` or a number"))),
+      None => Err((i, "expected `[` or a number".to_string())),
+    };
+  }
+
+  fn parse_list(line: &[char], i: usize) -> ParseResult<(Self, usize)> {
+    let mut packets = vec![];
+
+    let mut i = i + 1;
+    if line.get(i) == Some(&']') {
+      return Ok((Self::List(packets), i + 1));
+    }
+    loop {
+      let (packet, next) = Self::parse(line, i)?;
+      packets.push(packet);
+      match line.get(next) {
+        Some(',') => i = next + 1,
+        Some(']') => return Ok((Self::List(packets), next + 1)),
+        Some(c) => return Err((next, format!("unexpected `{c}`, expected `,` or `]`"))),
+        None => return Err((next, "expected `,` or `]`".to_string())),
+      }
+    }
+  }
+
+  fn parse_number(line: &[char], i: usize) -> ParseResult<(Self, usize)> {
+    let number_raw = line[i..]
+      .iter()
+      .take_while(|c| c.is_ascii_digit())
+      .collect::<String>();
+
+    let number = number_raw
+      .parse()
+      .map_err(|e| (i, format!("invalid `{number_raw}`: {e}")))?;
+    return Ok((Self::Number(number), i + number_raw.len()));
+  }
+}
+
+impl Ord for Packet {
+  fn cmp(&self, other: &Self) -> Ordering {
+    return match (self, other) {
+      (Self::Number(a), Self::Number(b)) => a.cmp(b),
+      (Self::List(a), Self::List(b)) => a
+        .iter()
+        .zip(b)
+        .map(|(a, b)| a.cmp(b))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(a.len().cmp(&b.len())),
+      (Self::List(_), Self::Number(b)) => self.cmp(&Self::List(vec![Self::Number(*b)])),
+      (Self::Number(a), Self::List(_)) => Self::List(vec![Self::Number(*a)]).cmp(other),
+    };
+  }
+}
+
+impl PartialOrd for Packet {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    return Some(self.cmp(other));
+  }
+}
@@ -0,0 +1,207 @@
+use std::fmt::Display;
+
+use crate::{self as aoc, Bounds2, Direction8, Grid, Lines, Point2, Result};
+
+pub fn solution() -> Box<dyn aoc::Solution> {
+  return aoc::solve(Day14);
+}
+
+struct Day14;
+
+impl aoc::Solver for Day14 {
+  type Input = Cave;
+  type Output1 = usize;
+  type Output2 = usize;
+
+  fn parse(&self, input: Lines) -> Result<Cave> {
+    let lines = input.all()?;
+    let mut rocks = vec![];
+    for line in &lines {
+      let points = line
+        .split(" -> ")
+        .map(|p| {
+          let (x, y) = p
+            .split_once(",")
+            .ok_or_else(|| line.error(p, format!("expected `x,y` but got `{p}`")))?;
+          return Ok(Point::new(line.parse(x)?, line.parse(y)?));
+        })
+        .collect::<Result<Vec<Point>>>()?;
+
+      for segment in points.windows(2) {
+        let [Point { x: a, y: b }, Point { x: c, y: d }] = segment else {
+          unreachable!();
+        };
+        let (&a, &c) = if a > c { (c, a) } else { (a, c) };
+        let (&b, &d) = if b > d { (d, b) } else { (b, d) };
+        for x in a..=c {
+          for y in b..=d {
+            rocks.push(Point::new(x, y));
+          }
+        }
+      }
+    }
+    return Ok(Cave::new(&rocks));
+  }
+
+  fn part1(&self, data: &Cave) -> Result<usize> {
+    return Ok(p1(data));
+  }
+
+  fn part2(&self, data: &Cave) -> Result<usize> {
+    return Ok(p2(data));
+  }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Tile {
+  Air,
+  Start,
+  Rock,
+  Sand,
+}
+
+/// (X,Y) coordinates. Higher Y means lower vertically.
+type Point = Point2<u64>;
+
+/// The slice of the cave where sand can fall, from `min_x` to `max_x` and from
+/// the top down to the `abyss`.
+#[derive(Clone, Debug)]
+struct Cave {
+  grid: Grid<Tile>,
+  abyss: u64,
+  min_x: u64,
+  max_x: u64,
+}
+
+const START: Point = Point::new(500, 0);
+
+impl Cave {
+  fn new(rocks: &[Point]) -> Self {
+    let bounds = Bounds2::from_points(rocks.iter().copied().chain([START])).unwrap();
+    let abyss = bounds.max.y + 3;
+    let min_x = bounds.min.x - 3;
+    let max_x = bounds.max.x + 3;
+
+    let width = (max_x - min_x + 1) as usize;
+    let mut cave = Self {
+      grid: Grid::new(width, abyss as usize, Tile::Air),
+      abyss,
+      min_x,
+      max_x,
+    };
+    for rock in rocks {
+      *cave.tile(rock) = Tile::Rock;
+    }
+    *cave.tile(&START) = Tile::Start;
+    return cave;
+  }
+
+  fn tile(&mut self, p: &Point) -> &mut Tile {
+    return &mut self.grid[((p.x - self.min_x) as usize, p.y as usize)];
+  }
+
+  fn add_sand(&mut self, p: &Point) -> Option<bool> {
+    let can_move = self.try_spot(&p.step(Direction8::Down))?
+      || self.try_spot(&p.step(Direction8::DownLeft))?
+      || self.try_spot(&p.step(Direction8::DownRight))?;
+
+    if !can_move {
+      *self.tile(p) = Tile::Sand;
+    }
+    return Some(true);
+  }
+
+  fn try_spot(&mut self, p: &Point) -> Option<bool> {
+    if p.y >= self.abyss {
+      return None;
+    }
+    if p.x <= self.min_x || p.x >= self.max_x {
+      return Some(false);
+    }
+    if *self.tile(p) == Tile::Air {
+      return self.add_sand(p);
+    }
+    return Some(false);
+  }
+}
+
+fn p1(data: &Cave) -> usize {
+  let mut data = data.clone();
+
+  while data.add_sand(&START).is_some() {}
+
+  return data.grid.iter().filter(|&tile| *tile == Tile::Sand).count();
+}
+
+/// Originally this ran a full simulation taking about half a second. Not bad.
+/// But we don't have to actually simulate the entire horizontal space, we can
+/// instead limit our simulation to a few steps left-and-right from the farthest
+/// rock. Then we can assume the rest will always be full.
+///
+/// This optimization cuts down the problem from O(H²) to O(H*W).
+/// on my PC this goes from 500ms to 40ms
+fn p2(data: &Cave) -> usize {
+  let mut data = data.clone();
+  let min_x = data.min_x;
+  let max_x = data.max_x;
+  let floor = data.abyss - 1;
+
+  for x in min_x..=max_x {
+    *data.tile(&Point::new(x, floor)) = Tile::Rock;
+  }
+
+  while *data.tile(&START) == Tile::Start {
+    data.add_sand(&START);
+  }
+
+  let sand_tiles = data
+    .grid
+    .enumerate()
+    .filter(|&(_, tile)| *tile == Tile::Sand)
+    .map(|((x, y), _)| Point::new(x as u64 + min_x, y as u64))
+    .collect::<Vec<_>>();
+
+  let min_x = sand_tiles.iter().map(|Point { x, .. }| x).min().unwrap();
+  let max_x = sand_tiles.iter().map(|Point { x, .. }| x).max().unwrap();
+
+  let air_height_left = sand_tiles
+    .iter()
+    .filter_map(|Point { x, y }| if x == min_x { Some(y) } else { None })
+    .min()
+    .unwrap();
+  let air_height_right = sand_tiles
+    .iter()
+    .filter_map(|Point { x, y }| if x == max_x { Some(y) } else { None })
+    .min()
+    .unwrap();
+
+  let adjust = data.abyss - 2;
+  let min_left = adjust - air_height_left;
+  let min_right = adjust - air_height_right;
+
+  let left_imaginary_sand = sum_zero_to_n(min_left) as usize;
+  let right_imaginary_sand = sum_zero_to_n(min_right) as usize;
+  return sand_tiles.len() + left_imaginary_sand + right_imaginary_sand;
+}
+
+fn sum_zero_to_n(n: u64) -> u64 {
+  return n * (n + 1) / 2;
+}
+
+impl Display for Tile {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let c = match self {
+      Tile::Air => ' ',
+      Tile::Start => '•',
+      Tile::Rock => '█',
+      Tile::Sand => '▒',
+    };
+    return write!(f, "{c}");
+  }
+}
+
+impl Display for Cave {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    return write!(f, "{}", self.grid);
+  }
+}
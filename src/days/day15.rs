@@ -1,55 +1,58 @@
 use ranges::Ranges;
 use std::collections::HashSet;
 
-use aoc;
-
-fn main() {
-  aoc::solve(
-    &|path| {
-      return aoc::lines(path)
-        .iter()
-        .map(|line| {
-          // Sensor at x=9, y=16: closest beacon is at x=10, y=16
-          let mut parts = line
-            .split_terminator([',', '=', ' ', ':'])
-            .filter_map(|part| part.parse::<i64>().ok());
-
-          let sensor = Point {
-            x: parts.next().unwrap(),
-            y: parts.next().unwrap(),
-          };
-          let beacon = Point {
-            x: parts.next().unwrap(),
-            y: parts.next().unwrap(),
-          };
-          let distance = sensor.manhattan(&beacon);
-          return (sensor, beacon, distance);
-        })
-        .collect::<Vec<_>>();
-    },
-    &p1,
-    &p2,
-  );
-}
+use crate::{self as aoc, Error, Lines, Point2, Result};
 
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
-struct Point {
-  x: i64,
-  y: i64,
+pub fn solution() -> Box<dyn aoc::Solution> {
+  return aoc::solve(Day15);
 }
 
-impl Point {
-  fn manhattan(&self, other: &Self) -> u64 {
-    return self.x.abs_diff(other.x) + self.y.abs_diff(other.y);
+struct Day15;
+
+impl aoc::Solver for Day15 {
+  type Input = Vec<(Point, Point, u64)>;
+  type Output1 = usize;
+  type Output2 = i64;
+
+  fn parse(&self, input: Lines) -> Result<Vec<(Point, Point, u64)>> {
+    let lines = input.all()?;
+    return lines
+      .iter()
+      .map(|line| {
+        let (sx, sy, bx, by) = aoc::scan!(
+          line,
+          "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+          i64,
+          i64,
+          i64,
+          i64
+        )?;
+
+        let sensor = Point::new(sx, sy);
+        let beacon = Point::new(bx, by);
+        let distance = sensor.manhattan(&beacon);
+        return Ok((sensor, beacon, distance));
+      })
+      .collect();
+  }
+
+  fn part1(&self, data: &Vec<(Point, Point, u64)>) -> Result<usize> {
+    return Ok(p1(data));
+  }
+
+  fn part2(&self, data: &Vec<(Point, Point, u64)>) -> Result<i64> {
+    return p2(data);
   }
 }
 
-fn p1(data: Vec<(Point, Point, u64)>) -> usize {
+type Point = Point2<i64>;
+
+fn p1(data: &[(Point, Point, u64)]) -> usize {
   let target = if data.len() < 20 { 10 } else { 2_000_000 };
 
   let taken = data
     .iter()
-    .map(|(_, b, _)| b.clone())
+    .map(|(_, b, _)| *b)
     .filter(|p| p.y == target)
     .collect::<HashSet<_>>();
 
@@ -64,8 +67,10 @@ fn p1(data: Vec<(Point, Point, u64)>) -> usize {
     })
     // This can be optimized by merging the ranges
     .map(|(x, n)| Ranges::from((x - n as i64)..=(x + n as i64)))
-    .reduce(|acc, v| acc | v)
-    .unwrap();
+    .reduce(|acc, v| acc | v);
+  let Some(scanned) = scanned else {
+    return 0;
+  };
 
   // For whatever reason the ranges crate doesn't have a proper "count" function.
   let count: usize = scanned
@@ -77,7 +82,7 @@ fn p1(data: Vec<(Point, Point, u64)>) -> usize {
   return count - taken.len();
 }
 
-fn p2(data: Vec<(Point, Point, u64)>) -> i64 {
+fn p2(data: &[(Point, Point, u64)]) -> Result<i64> {
   let seach_space = if data.len() < 20 { 20 } else { 4_000_000 };
   let range = 0..=seach_space;
 
@@ -99,7 +104,7 @@ fn p2(data: Vec<(Point, Point, u64)>) -> i64 {
         .chain(diag_right_up)
         .chain(diag_right_down)
         .filter(|(x, y)| range.contains(x) && range.contains(y))
-        .map(|(x, y)| Point { x, y })
+        .map(|(x, y)| Point::new(x, y))
     })
     .collect::<HashSet<_>>();
 
@@ -109,11 +114,10 @@ fn p2(data: Vec<(Point, Point, u64)>) -> i64 {
   // the input data didn't have such case.
   let unscanned = perimeter
     .iter()
-    .filter(|p| data.iter().all(|(s, _, d)| p.manhattan(s) > *d))
-    .next()
-    .unwrap();
+    .find(|p| data.iter().all(|(s, _, d)| p.manhattan(s) > *d))
+    .ok_or_else(|| Error::new("the beacon could be anywhere"))?;
 
-  return tuning_frequency(unscanned);
+  return Ok(tuning_frequency(unscanned));
 }
 
 fn tuning_frequency(p: &Point) -> i64 {
@@ -1,40 +1,69 @@
-use std::collections::{HashMap, HashSet, LinkedList};
+use std::collections::HashMap;
 
-use aoc;
+use crate::{self as aoc, Error, Lines, Result};
 
-fn main() {
-  aoc::solve(
-    &|path| {
-      let valves = aoc::lines(path)
-        .iter()
-        .map(|line| {
-          let mut parts = line
-            .split_terminator([' ', '=', ',', ';'])
-            .filter(|part| !part.is_empty())
-            .skip(1);
-          let name = parts.next().unwrap().to_string();
-          let mut parts = parts.skip(3);
-          let flow = parts.next().unwrap().parse().unwrap();
-          let parts = parts.skip(4);
-          let valves = parts.map(|s| s.to_string()).collect::<Vec<String>>();
+pub fn solution() -> Box<dyn aoc::Solution> {
+  return aoc::solve(Day16);
+}
 
-          return (name, flow, valves);
-        })
-        .collect::<Vec<_>>();
+struct Day16;
 
-      let name_to_index = valves
-        .iter()
-        .enumerate()
-        .map(|(i, (name, ..))| (name.clone(), i))
-        .collect::<HashMap<_, _>>();
+impl aoc::Solver for Day16 {
+  type Input = Vec<Valve>;
+  type Output1 = usize;
+  type Output2 = usize;
+
+  fn parse(&self, input: Lines) -> Result<Vec<Valve>> {
+    let lines = input.all()?;
+    let valves = lines
+      .iter()
+      .map(|line| {
+        // Either "tunnels lead to valves DD, II" or "tunnel leads to valve GG".
+        let [name, flow, _, _, _, valves] =
+          line.scan("Valve {} has flow rate={}; tunnel{} lead{} to valve{} {}")?;
+        let valves = valves.split(", ").collect();
+        return Ok((name.to_string(), line.parse(flow)?, valves));
+      })
+      .collect::<Result<Vec<(String, usize, Vec<&str>)>>>()?;
 
-      return valves
+    // Valves are kept in a `BitMask`.
+    if valves.len() > 64 {
+      return Err(Error::new(format!(
+        "expected at most 64 valves but found {}",
+        valves.len()
+      )));
+    }
+
+    let name_to_index = valves
+      .iter()
+      .enumerate()
+      .map(|(i, (name, ..))| (name.clone(), i))
+      .collect::<HashMap<_, _>>();
+
+    let valves = valves
+      .iter()
+      .zip(&lines)
+      .map(|((name, flow, valves), line)| {
+        let edges = valves
+          .iter()
+          .map(|other| match name_to_index.get(*other) {
+            Some(&i) => Ok(i),
+            None => Err(line.error(other, format!("unknown valve `{other}`"))),
+          })
+          .collect::<Result<Vec<usize>>>()?;
+        return Ok((name.clone(), *flow, edges));
+      })
+      .collect::<Result<Vec<_>>>()?;
+
+    return Ok(
+      valves
         .iter()
-        .map(|(name, flow, _)| {
-          let edges = valves
-            .iter()
-            .map(|(other, ..)| shortest_path(name, other, &valves, &name_to_index))
-            .enumerate()
+        .enumerate()
+        .map(|(from, (name, flow, _))| {
+          // Number of tunnels to every valve connected to this one.
+          let distances = aoc::bfs_distances([from], |&valve| valves[valve].2.iter().copied());
+          let edges = (0..valves.len())
+            .filter_map(|to| Some((to, *distances.get(&to)?)))
             .filter(|&(i, cost)| cost > 0 && valves[i].1 > 0)
             .collect::<Vec<_>>();
           return Valve {
@@ -43,38 +72,46 @@ fn main() {
             edges,
           };
         })
-        .collect::<Vec<_>>();
-    },
-    &p1,
-    &p2,
-  );
-}
-
-fn shortest_path<T>(
-  from: &String,
-  to: &String,
-  valves: &Vec<(String, T, Vec<String>)>,
-  name_to_index: &HashMap<String, usize>,
-) -> usize {
-  let mut seen: HashSet<&String> = HashSet::new();
-  let mut queue: LinkedList<(usize, &String)> = LinkedList::from([(0, from)]);
-  while !queue.is_empty() {
-    let (cost, current) = queue.pop_front().unwrap();
-    if current == to {
-      return cost;
-    }
+        .collect(),
+    );
+  }
 
-    if !seen.insert(current) {
-      continue;
-    }
+  fn part1(&self, data: &Vec<Valve>) -> Result<usize> {
+    let valve_state = BitMask(0);
+    const DAYS: usize = 30;
+    let start = start(data)?;
+    return Ok(mochila(
+      data,
+      valve_state,
+      &start,
+      // This way we simulate only having one worker because it'll never become available.
+      (&0, usize::MAX),
+      0,
+      DAYS,
+    ));
+  }
 
-    let (.., connects_to) = &valves[*name_to_index.get(current).unwrap()];
-    for name in connects_to {
-      queue.push_back((cost + 1, name))
-    }
+  fn part2(&self, data: &Vec<Valve>) -> Result<usize> {
+    let valve_state = BitMask(0);
+    const DAYS: usize = 30;
+    const ELEPHANT_TRAINING: usize = 4;
+    let start = start(data)?;
+    return Ok(parallel_mochila(
+      data,
+      &start,
+      &start,
+      valve_state,
+      DAYS - ELEPHANT_TRAINING,
+      0,
+    ));
   }
+}
 
-  panic!("not a fully connected graph!");
+fn start(data: &[Valve]) -> Result<usize> {
+  return data
+    .iter()
+    .position(|v| v.name == "AA")
+    .ok_or_else(|| Error::new("there is no valve `AA` to start from"));
 }
 
 #[derive(Hash, Clone, Debug)]
@@ -112,38 +149,8 @@ impl BitMask {
   }
 }
 
-fn p1(data: Vec<Valve>) -> usize {
-  let valve_state = BitMask(0);
-  const DAYS: usize = 30;
-  let start = data.iter().position(|v| v.name == "AA").unwrap();
-  return mochila(
-    &data,
-    valve_state,
-    &start,
-    // This way we simulate only having one worker because it'll never become available.
-    (&0, std::usize::MAX),
-    0,
-    DAYS,
-  );
-}
-
-fn p2(data: Vec<Valve>) -> usize {
-  let valve_state = BitMask(0);
-  const DAYS: usize = 30;
-  const ELEPHANT_TRAINING: usize = 4;
-  let start = data.iter().position(|v| v.name == "AA").unwrap();
-  return parallel_mochila(
-    &data,
-    &start,
-    &start,
-    valve_state,
-    DAYS - ELEPHANT_TRAINING,
-    0,
-  );
-}
-
 fn parallel_mochila(
-  data: &Vec<Valve>,
+  data: &[Valve],
   a: &usize,
   b: &usize,
   valve_state: BitMask,
@@ -172,7 +179,7 @@ fn parallel_mochila(
             data,
             valve_state.open(a),
             a,
-            (&0, std::usize::MAX),
+            (&0, usize::MAX),
             total_flow,
             limit - cost,
           )
@@ -206,7 +213,7 @@ fn parallel_mochila(
 }
 
 fn mochila(
-  data: &Vec<Valve>,
+  data: &[Valve],
   valve_state: BitMask,
   active: &usize,
   (limbo, limbo_left): (&usize, usize),
@@ -227,7 +234,6 @@ fn mochila(
     .next_edges(&valve_state, &limit)
     .iter()
     .map(|(valve, cost)| {
-      let cost = cost;
       let valve_state = valve_state.open(valve);
 
       if limbo_left >= *cost {
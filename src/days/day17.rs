@@ -1,16 +1,37 @@
-use std::{
-  collections::{HashMap, HashSet},
-  ops::RangeInclusive,
-};
+use std::{collections::HashMap, ops::RangeInclusive};
 
-use aoc;
+use crate::{self as aoc, Grid, Lines, Point2, Result};
 
-fn main() {
-  aoc::solve(
-    &|path| aoc::lines(path)[0].chars().collect::<Vec<_>>(),
-    &p1,
-    &p2,
-  );
+pub fn solution() -> Box<dyn aoc::Solution> {
+  return aoc::solve(Day17);
+}
+
+struct Day17;
+
+impl aoc::Solver for Day17 {
+  type Input = Vec<char>;
+  type Output1 = u64;
+  type Output2 = u64;
+
+  fn parse(&self, input: Lines) -> Result<Vec<char>> {
+    let lines = input.all()?;
+    let line = &lines[0];
+    if line.is_empty() {
+      return Err(line.error_at(1, "expected the jet pattern"));
+    }
+    if let Some((i, c)) = line.char_indices().find(|&(_, c)| c != '<' && c != '>') {
+      return Err(line.error(&line[i..], format!("unexpected `{c}`, expected `<` or `>`")));
+    }
+    return Ok(line.chars().collect());
+  }
+
+  fn part1(&self, data: &Vec<char>) -> Result<u64> {
+    return Ok(simulate(data, 2022));
+  }
+
+  fn part2(&self, data: &Vec<char>) -> Result<u64> {
+    return Ok(simulate(data, 1000000000000));
+  }
 }
 
 #[derive(Clone, PartialEq)]
@@ -21,38 +42,58 @@ struct Figure {
 }
 
 impl Figure {
-  fn push<F>(&self, blocks: &HashSet<Point>, transform: F) -> Figure
+  fn push<F>(&self, blocks: &Blocks, transform: F) -> Figure
   where
     F: Fn(&Point) -> Point,
   {
     let coords: Vec<_> = self.coords.iter().map(transform).collect();
     if coords
       .iter()
-      .any(|p @ &Point { x, y }| x <= 0 || x >= WALL || y <= 0 || blocks.contains(p))
+      .any(|p @ &Point { x, y }| x == 0 || x >= WALL || y == 0 || blocks.contains(p))
     {
       return self.clone();
     }
     return Figure { coords };
   }
 
-  fn move_up(&self, blocks: &HashSet<Point>, delta: u64) -> Figure {
-    return self.push(blocks, |&Point { x, y }| Point { x, y: y + delta });
+  fn move_up(&self, blocks: &Blocks, delta: u64) -> Figure {
+    return self.push(blocks, |&p| p + Point::new(0, delta));
   }
-  fn push_left(&self, blocks: &HashSet<Point>) -> Figure {
-    return self.push(blocks, |&Point { x, y }| Point { x: x - 1, y });
+  fn push_left(&self, blocks: &Blocks) -> Figure {
+    return self.push(blocks, |&p| p - Point::new(1, 0));
   }
-  fn push_right(&self, blocks: &HashSet<Point>) -> Figure {
-    return self.push(blocks, |&Point { x, y }| Point { x: x + 1, y });
+  fn push_right(&self, blocks: &Blocks) -> Figure {
+    return self.push(blocks, |&p| p + Point::new(1, 0));
   }
-  fn fall(&self, blocks: &HashSet<Point>) -> Figure {
-    return self.push(blocks, |&Point { x, y }| Point { x, y: y - 1 });
+  fn fall(&self, blocks: &Blocks) -> Figure {
+    return self.push(blocks, |&p| p - Point::new(0, 1));
   }
 }
 
-#[derive(Clone, Hash, PartialEq, Eq, Debug)]
-struct Point {
-  x: u64,
-  y: u64,
+type Point = Point2<u64>;
+
+/// The settled rocks between the walls and above the floor, growing upwards.
+/// Row `y - 1` of the grid is at height `y`, column `x - 1` at `x`.
+struct Blocks(Grid<bool>);
+
+impl Blocks {
+  fn new() -> Self {
+    return Blocks(Grid::new((WALL - 1) as usize, 0, false));
+  }
+
+  fn contains(&self, &Point { x, y }: &Point) -> bool {
+    let (Some(x), Some(y)) = (x.checked_sub(1), y.checked_sub(1)) else {
+      return false;
+    };
+    return self.0.get((x as usize, y as usize)) == Some(&true);
+  }
+
+  fn insert(&mut self, Point { x, y }: Point) {
+    while (self.0.height() as u64) < y {
+      self.0.push_row([false; (WALL - 1) as usize]);
+    }
+    self.0[(x as usize - 1, y as usize - 1)] = true;
+  }
 }
 
 /// The problem says the gap is 3, so the delta is N+1.
@@ -62,114 +103,22 @@ const DELTA_FROM_TALLEST: u64 = 4;
 const DELTA_FROM_LEFT: u64 = 3;
 
 fn shapes() -> Vec<Figure> {
+  let at = |x, y| Point::new(DELTA_FROM_LEFT + x, y);
   return vec![
     Figure {
-      coords: vec![
-        Point {
-          x: DELTA_FROM_LEFT,
-          y: 0,
-        },
-        Point {
-          x: DELTA_FROM_LEFT + 1,
-          y: 0,
-        },
-        Point {
-          x: DELTA_FROM_LEFT + 2,
-          y: 0,
-        },
-        Point {
-          x: DELTA_FROM_LEFT + 3,
-          y: 0,
-        },
-      ],
+      coords: vec![at(0, 0), at(1, 0), at(2, 0), at(3, 0)],
     },
     Figure {
-      coords: vec![
-        Point {
-          x: DELTA_FROM_LEFT + 1,
-          y: 2,
-        },
-        Point {
-          x: DELTA_FROM_LEFT,
-          y: 1,
-        },
-        Point {
-          x: DELTA_FROM_LEFT + 1,
-          y: 1,
-        },
-        Point {
-          x: DELTA_FROM_LEFT + 2,
-          y: 1,
-        },
-        Point {
-          x: DELTA_FROM_LEFT + 1,
-          y: 0,
-        },
-      ],
+      coords: vec![at(1, 2), at(0, 1), at(1, 1), at(2, 1), at(1, 0)],
     },
     Figure {
-      coords: vec![
-        Point {
-          x: DELTA_FROM_LEFT + 2,
-          y: 2,
-        },
-        Point {
-          x: DELTA_FROM_LEFT + 2,
-          y: 1,
-        },
-        Point {
-          x: DELTA_FROM_LEFT + 2,
-          y: 0,
-        },
-        Point {
-          x: DELTA_FROM_LEFT + 1,
-          y: 0,
-        },
-        Point {
-          x: DELTA_FROM_LEFT,
-          y: 0,
-        },
-      ],
+      coords: vec![at(2, 2), at(2, 1), at(2, 0), at(1, 0), at(0, 0)],
     },
     Figure {
-      coords: vec![
-        Point {
-          x: DELTA_FROM_LEFT,
-          y: 3,
-        },
-        Point {
-          x: DELTA_FROM_LEFT,
-          y: 2,
-        },
-        Point {
-          x: DELTA_FROM_LEFT,
-          y: 1,
-        },
-        Point {
-          x: DELTA_FROM_LEFT,
-          y: 0,
-        },
-      ],
+      coords: vec![at(0, 3), at(0, 2), at(0, 1), at(0, 0)],
     },
     Figure {
-      coords: vec![
-        Point {
-          x: DELTA_FROM_LEFT,
-          y: 1,
-        },
-        Point {
-          x: DELTA_FROM_LEFT + 1,
-          y: 1,
-        },
-        Point {
-          x: DELTA_FROM_LEFT,
-          y: 0,
-        },
-        Point {
-          x: DELTA_FROM_LEFT + 1,
-          y: 0,
-        },
-      ],
+      coords: vec![at(0, 1), at(1, 1), at(0, 0), at(1, 0)],
     },
   ];
 }
@@ -177,22 +126,14 @@ fn shapes() -> Vec<Figure> {
 /// There are walls 0 and 8. That leaves 7 spaces in between.
 const WALL: u64 = 8;
 
-fn p1(data: Vec<char>) -> u64 {
-  return simulate(data, 2022);
-}
-
-fn p2(data: Vec<char>) -> u64 {
-  return simulate(data, 1000000000000);
-}
-
 // I have no idea why I only have to look at two lines.
 const SEARCH_SPACE: u64 = 2;
 
-fn simulate(data: Vec<char>, count: usize) -> u64 {
+fn simulate(data: &[char], count: usize) -> u64 {
   let before_dp_starts = data.len(); // just so that things are settled.
   let mut dp: HashMap<(usize, usize, String), (u64, usize)> = HashMap::new();
 
-  let mut blocks: HashSet<Point> = HashSet::new();
+  let mut blocks = Blocks::new();
   let mut highest = 0;
   let mut extra_height = 0; // Needed so we don't mess up with the algorithm.
 
@@ -248,11 +189,7 @@ fn simulate(data: Vec<char>, count: usize) -> u64 {
   return highest + extra_height;
 }
 
-fn stringify_grid(
-  blocks: &HashSet<Point>,
-  figure: Option<&Figure>,
-  r: RangeInclusive<u64>,
-) -> String {
+fn stringify_grid(blocks: &Blocks, figure: Option<&Figure>, r: RangeInclusive<u64>) -> String {
   return r
     .rev()
     .flat_map(|y| {
@@ -268,6 +205,6 @@ fn stringify_grid(
     .collect();
 }
 
-fn _print_grid(blocks: &HashSet<Point>, figure: Option<&Figure>, r: RangeInclusive<u64>) {
+fn _print_grid(blocks: &Blocks, figure: Option<&Figure>, r: RangeInclusive<u64>) {
   println!("{}", stringify_grid(blocks, figure, r));
 }
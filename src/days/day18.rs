@@ -0,0 +1,77 @@
+use std::collections::HashSet;
+
+use crate::{self as aoc, Bounds3, Lines, Point3, Result};
+
+pub fn solution() -> Box<dyn aoc::Solution> {
+  return aoc::solve(Day18);
+}
+
+struct Day18;
+
+impl aoc::Solver for Day18 {
+  type Input = HashSet<Point>;
+  type Output1 = usize;
+  type Output2 = usize;
+
+  fn parse(&self, input: Lines) -> Result<HashSet<Point>> {
+    let lines = input.all()?;
+    return lines
+      .iter()
+      .map(|line| {
+        let coords = line
+          .split(",")
+          .map(|part| {
+            let n = line.parse(part)?;
+            // `part2` only searches around the cubes inside of this area.
+            if !(0..SEARCH_AREA - 1).contains(&n) {
+              let message = format!("expected a number from 0 to {}", SEARCH_AREA - 2);
+              return Err(line.error(part, message));
+            }
+            return Ok(n);
+          })
+          .collect::<Result<Vec<i64>>>()?;
+        let [x, y, z] = coords[..] else {
+          let message = format!("expected 3 coordinates but found {}", coords.len());
+          return Err(line.error_at(1, message));
+        };
+        return Ok(Point::new(x, y, z));
+      })
+      .collect();
+  }
+
+  fn part1(&self, data: &HashSet<Point>) -> Result<usize> {
+    return Ok(
+      data
+        .iter()
+        .map(|p| 6 - p.neighbours6().iter().filter(|p| data.contains(p)).count())
+        .sum(),
+    );
+  }
+
+  fn part2(&self, data: &HashSet<Point>) -> Result<usize> {
+    return Ok(p2(data));
+  }
+}
+
+type Point = Point3<i64>;
+
+const SEARCH_AREA: i64 = 30;
+
+/// Counts the faces touched by the air flooding in from outside of the cubes.
+fn p2(data: &HashSet<Point>) -> usize {
+  let area = &Bounds3 {
+    min: Point::new(-1, -1, -1),
+    max: Point::new(SEARCH_AREA - 1, SEARCH_AREA - 1, SEARCH_AREA - 1),
+  };
+  // The corner is always outside, the cubes are from 0 to `SEARCH_AREA - 2`.
+  let air = aoc::bfs_distances([area.min], |p| {
+    p.neighbours6()
+      .into_iter()
+      .filter(move |n| area.contains(n) && !data.contains(n))
+  });
+
+  return air
+    .keys()
+    .map(|p| p.neighbours6().iter().filter(|n| data.contains(n)).count())
+    .sum();
+}
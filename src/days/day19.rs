@@ -1,19 +1,38 @@
 use std::collections::HashSet;
 
-use aoc;
+use crate::{self as aoc, Line, Lines, Result};
 use rayon::prelude::*;
 
-fn main() {
-  aoc::solve(
-    &|path| {
-      aoc::lines(path)
+pub fn solution() -> Box<dyn aoc::Solution> {
+  return aoc::solve(Day19);
+}
+
+struct Day19;
+
+impl aoc::Solver for Day19 {
+  type Input = Vec<Blueprint>;
+  type Output1 = usize;
+  type Output2 = usize;
+
+  fn parse(&self, input: Lines) -> Result<Vec<Blueprint>> {
+    let lines = input.all()?;
+    return lines.iter().map(Blueprint::parse).collect();
+  }
+
+  fn part1(&self, data: &Vec<Blueprint>) -> Result<usize> {
+    return Ok(
+      solve(data, 24)
         .iter()
-        .map(|line| Blueprint::from(line))
-        .collect::<Vec<_>>()
-    },
-    &p1,
-    &p2,
-  );
+        .enumerate()
+        .map(|(i, v)| (i + 1) * v)
+        .sum(),
+    );
+  }
+
+  fn part2(&self, data: &Vec<Blueprint>) -> Result<usize> {
+    let data = &data[..data.len().min(3)];
+    return Ok(solve(data, 32).iter().product());
+  }
 }
 
 #[derive(Clone)]
@@ -24,40 +43,38 @@ struct Blueprint {
   geode: Bag,
 }
 
-impl From<&String> for Blueprint {
-  fn from(line: &String) -> Self {
-    let mut iter = line
-      .split_terminator([' ', ':', '.'])
-      .filter(|s| !s.is_empty())
-      .filter_map(|s| s.parse::<u128>().ok())
-      .skip(1);
-
-    return Self {
-      ore: Bag(iter.next().unwrap()),
-      clay: Bag(iter.next().unwrap()),
-      obsidian: Bag(iter.next().unwrap() + (iter.next().unwrap() << 32)),
-      geode: Bag(iter.next().unwrap() + (iter.next().unwrap() << 64)),
+impl Blueprint {
+  fn parse(line: &Line) -> Result<Self> {
+    // Each resource takes 32 bits of a `Bag`, so the costs must fit in them.
+    let (_, ore, clay, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian) = aoc::scan!(
+      line,
+      "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+       Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.",
+      usize,
+      u32,
+      u32,
+      u32,
+      u32,
+      u32,
+      u32
+    )?;
+    let bag = |ore: u32, clay: u32, obsidian: u32| {
+      return Bag(ore as u128 + ((clay as u128) << 32) + ((obsidian as u128) << 64));
     };
+
+    return Ok(Self {
+      ore: bag(ore, 0, 0),
+      clay: bag(clay, 0, 0),
+      obsidian: bag(obsidian_ore, obsidian_clay, 0),
+      geode: bag(geode_ore, 0, geode_obsidian),
+    });
   }
 }
 
 #[derive(Clone, Eq, PartialEq, Hash, Debug, PartialOrd, Ord)]
 struct Bag(u128);
 
-fn p1(data: Vec<Blueprint>) -> usize {
-  return solve(data, 24)
-    .iter()
-    .enumerate()
-    .map(|(i, v)| (i + 1) * v)
-    .sum();
-}
-
-fn p2(mut data: Vec<Blueprint>) -> usize {
-  data.truncate(3);
-  return solve(data, 32).iter().fold(1, |acc, v| acc * v);
-}
-
-fn solve(data: Vec<Blueprint>, time: usize) -> Vec<usize> {
+fn solve(data: &[Blueprint], time: usize) -> Vec<usize> {
   return data
     .par_iter()
     .map(|b| b.eval(time, Bag(0), Bag(1)))
@@ -75,22 +92,22 @@ impl Blueprint {
     let mut options = vec![(Bag(backpack.0 + robots.0), robots.clone(), geodes)];
 
     if backpack.contains(&self.geode) {
-      let bp = Bag(backpack.0 - &self.geode.0 + robots.0);
+      let bp = Bag(backpack.0 - self.geode.0 + robots.0);
       // Add all geodes that would be created by a geode robot during the time left.
       options.push((bp, robots.clone(), geodes + time - 1));
     }
     if backpack.contains(&self.obsidian) {
-      let bp = Bag(backpack.0 - &self.obsidian.0 + robots.0);
+      let bp = Bag(backpack.0 - self.obsidian.0 + robots.0);
       let r = Bag(robots.0 + (1 << 64));
       options.push((bp, r, geodes));
     }
     if backpack.contains(&self.clay) {
-      let bp = Bag(backpack.0 - &self.clay.0 + robots.0);
+      let bp = Bag(backpack.0 - self.clay.0 + robots.0);
       let r = Bag(robots.0 + (1 << 32));
       options.push((bp, r, geodes));
     }
     if backpack.contains(&self.ore) {
-      let bp = Bag(backpack.0 - &self.ore.0 + robots.0);
+      let bp = Bag(backpack.0 - self.ore.0 + robots.0);
       let r = Bag(robots.0 + 1);
       options.push((bp, r, geodes));
     }
@@ -134,7 +151,7 @@ impl Blueprint {
   }
 }
 
-const ORE_MASK: u128 = std::u32::MAX as u128;
+const ORE_MASK: u128 = u32::MAX as u128;
 const CLAY_MASK: u128 = ORE_MASK << 32;
 const OBSIDIAN_MASK: u128 = ORE_MASK << 64;
 
@@ -0,0 +1,80 @@
+use crate::{self as aoc, Lines, Result};
+
+pub fn solution() -> Box<dyn aoc::Solution> {
+  return aoc::solve(Day2);
+}
+
+struct Day2;
+
+impl aoc::Solver for Day2 {
+  type Input = Vec<(char, char)>;
+  type Output1 = i64;
+  type Output2 = i64;
+
+  fn parse(&self, input: Lines) -> Result<Vec<(char, char)>> {
+    let lines = input.all()?;
+    let mut data = lines
+      .iter()
+      .map(|line| {
+        let mut chars = line.chars();
+        return match (chars.next(), chars.last()) {
+          (Some(a @ 'A'..='C'), Some(b @ 'X'..='Z')) => Ok((a, b)),
+          _ => Err(line.error(line, "expected `A`, `B` or `C` and then `X`, `Y` or `Z`")),
+        };
+      })
+      .collect::<Result<Vec<(char, char)>>>()?;
+    data.sort();
+    return Ok(data);
+  }
+
+  fn part1(&self, data: &Vec<(char, char)>) -> Result<i64> {
+    return Ok(data.iter().map(|&round| check(round)).sum());
+  }
+
+  fn part2(&self, data: &Vec<(char, char)>) -> Result<i64> {
+    return Ok(
+      data
+        .iter()
+        // Translate from `(opponent, expected_outcome)` to `(opponent, me)`.
+        .map(|&(a, b)| match (a, b) {
+          // A: Pierda,   X: Lose,   X: Pierda
+          // B: Papel,    Y: Draw,   Y: Papel
+          // C: Tijera,   Z: Win,    Z: Tijera
+          ('A', 'X') => (a, 'Z'),
+          ('A', 'Y') => (a, 'X'),
+          ('A', 'Z') => (a, 'Y'),
+          ('B', 'X') => (a, 'X'),
+          ('B', 'Y') => (a, 'Y'),
+          ('B', 'Z') => (a, 'Z'),
+          ('C', 'X') => (a, 'Y'),
+          ('C', 'Y') => (a, 'Z'),
+          ('C', 'Z') => (a, 'X'),
+          _ => panic!("impossible!"),
+        })
+        .map(check)
+        .sum(),
+    );
+  }
+}
+
+/// Checks score from around with  `(opponent, me)`.
+///
+/// A: Pierda, X: Pierda
+/// B: Papel,  Y: Papel
+/// C: Tijera, Z: Tijera
+// The shape and the outcome are scored separately, even when one is 0.
+#[allow(clippy::identity_op)]
+fn check((a, b): (char, char)) -> i64 {
+  return match (a, b) {
+    ('A', 'X') => 1 + 3,
+    ('A', 'Y') => 2 + 6,
+    ('A', 'Z') => 3 + 0,
+    ('B', 'X') => 1 + 0,
+    ('B', 'Y') => 2 + 3,
+    ('B', 'Z') => 3 + 6,
+    ('C', 'X') => 1 + 6,
+    ('C', 'Y') => 2 + 0,
+    ('C', 'Z') => 3 + 3,
+    _ => panic!("impossible!"),
+  };
+}
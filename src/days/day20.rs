@@ -0,0 +1,128 @@
+use crate::{self as aoc, Error, Lines, Result};
+
+pub fn solution() -> Box<dyn aoc::Solution> {
+  return aoc::solve(Day20);
+}
+
+struct Day20;
+
+impl aoc::Solver for Day20 {
+  type Input = Vec<i64>;
+  type Output1 = i64;
+  type Output2 = i64;
+
+  fn parse(&self, input: Lines) -> Result<Vec<i64>> {
+    let lines = input.all()?;
+    let numbers = lines
+      .iter()
+      .map(|line| line.parse(line))
+      .collect::<Result<Vec<i64>>>()?;
+    if !numbers.contains(&0) {
+      return Err(Error::new(
+        "there is no 0 to find the grove coordinates from",
+      ));
+    }
+    return Ok(numbers);
+  }
+
+  fn part1(&self, data: &Vec<i64>) -> Result<i64> {
+    return Ok(decrypt(data, 1, 1));
+  }
+
+  fn part2(&self, data: &Vec<i64>) -> Result<i64> {
+    const DECRYPTION_KEY: i64 = 811_589_153;
+    return Ok(decrypt(data, DECRYPTION_KEY, 10));
+  }
+}
+
+fn decrypt(data: &[i64], key: i64, rounds: usize) -> i64 {
+  let numbers = data.iter().map(|n| n * key).collect::<Vec<_>>();
+  let mut circle = Circle::new(numbers.len());
+  for _ in 0..rounds {
+    mix(&mut circle, &numbers);
+  }
+
+  let order = circle.order().collect::<Vec<_>>();
+  let zero = order.iter().position(|&i| numbers[i] == 0).unwrap();
+  return [1000, 2000, 3000]
+    .iter()
+    .map(|offset| numbers[order[(zero + offset) % order.len()]])
+    .sum();
+}
+
+/// Moves every number, in the order of the file, as many places as its value.
+fn mix(circle: &mut Circle, numbers: &[i64]) {
+  // Once a number is taken out there are `n - 1` places where it can go, and
+  // going around all of them leaves it where it was.
+  let places = numbers.len() as i64 - 1;
+  if places == 0 {
+    return;
+  }
+  for (i, n) in numbers.iter().enumerate() {
+    let from = circle.remove(i);
+    let to = (from as i64 + n).rem_euclid(places);
+    circle.insert(to as usize, i);
+  }
+}
+
+/// Indexes of the numbers in the order they are in the circle, split in
+/// buckets of about √n so that a move shifts a bucket rather than the whole
+/// file, and finding a number only walks the bucket sizes.
+struct Circle {
+  buckets: Vec<Vec<usize>>,
+  /// Which bucket each number is in.
+  bucket_of: Vec<usize>,
+  size: usize,
+}
+
+impl Circle {
+  fn new(len: usize) -> Self {
+    let mut circle = Circle {
+      buckets: vec![],
+      bucket_of: vec![0; len],
+      size: ((len as f64).sqrt() as usize).max(1),
+    };
+    circle.rebalance((0..len).collect());
+    return circle;
+  }
+
+  fn rebalance(&mut self, order: Vec<usize>) {
+    self.buckets = order.chunks(self.size).map(|c| c.to_vec()).collect();
+    for (b, bucket) in self.buckets.iter().enumerate() {
+      for &i in bucket {
+        self.bucket_of[i] = b;
+      }
+    }
+  }
+
+  /// Takes number `i` out, returning where it was.
+  fn remove(&mut self, i: usize) -> usize {
+    let b = self.bucket_of[i];
+    let before = self.buckets[..b]
+      .iter()
+      .map(|bucket| bucket.len())
+      .sum::<usize>();
+    let offset = self.buckets[b].iter().position(|&n| n == i).unwrap();
+    self.buckets[b].remove(offset);
+    return before + offset;
+  }
+
+  fn insert(&mut self, mut at: usize, i: usize) {
+    let last = self.buckets.len() - 1;
+    for (b, bucket) in self.buckets.iter_mut().enumerate() {
+      if at <= bucket.len() || b == last {
+        bucket.insert(at.min(bucket.len()), i);
+        self.bucket_of[i] = b;
+        if bucket.len() > 2 * self.size {
+          self.rebalance(self.order().collect());
+        }
+        return;
+      }
+      at -= bucket.len();
+    }
+  }
+
+  fn order(&self) -> impl Iterator<Item = usize> + '_ {
+    return self.buckets.iter().flatten().copied();
+  }
+}
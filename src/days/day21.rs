@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+
+use crate::{self as aoc, Error, Lines, Result};
+
+pub fn solution() -> Box<dyn aoc::Solution> {
+  return aoc::solve(Day21);
+}
+
+struct Day21;
+
+impl aoc::Solver for Day21 {
+  type Input = Monkeys;
+  type Output1 = i64;
+  type Output2 = i64;
+
+  fn parse(&self, input: Lines) -> Result<Monkeys> {
+    let lines = input.all()?;
+    let jobs = lines
+      .iter()
+      .map(|line| {
+        let [name, job] = line.scan("{}: {}")?;
+        return Ok((line, name, job));
+      })
+      .collect::<Result<Vec<_>>>()?;
+
+    let mut index = HashMap::new();
+    for (i, (line, name, _)) in jobs.iter().enumerate() {
+      if index.insert(*name, i).is_some() {
+        return Err(line.error(name, format!("`{name}` already has a job")));
+      }
+    }
+    let monkey = |line: &aoc::Line, name: &str| -> Result<usize> {
+      return index
+        .get(name)
+        .copied()
+        .ok_or_else(|| line.error(name, format!("unknown monkey `{name}`")));
+    };
+
+    let names = jobs.iter().map(|(_, name, _)| name.to_string()).collect();
+    let jobs = jobs
+      .iter()
+      .map(|(line, _, job)| {
+        let parts = job.split(' ').collect::<Vec<_>>();
+        let [a, operator, b] = parts[..] else {
+          return Ok(Job::Number(line.parse(job)?));
+        };
+        let operator = match operator {
+          "+" => Operator::Add,
+          "-" => Operator::Subtract,
+          "*" => Operator::Multiply,
+          "/" => Operator::Divide,
+          other => return Err(line.error(other, format!("invalid operator `{other}`"))),
+        };
+        return Ok(Job::Operation(monkey(line, a)?, operator, monkey(line, b)?));
+      })
+      .collect::<Result<Vec<_>>>()?;
+
+    return Ok(Monkeys { names, jobs });
+  }
+
+  fn part1(&self, data: &Monkeys) -> Result<i64> {
+    return data.eval(data.find("root")?, 0);
+  }
+
+  fn part2(&self, data: &Monkeys) -> Result<i64> {
+    let (root, humn) = (data.find("root")?, data.find("humn")?);
+    let Job::Operation(left, _, right) = data.jobs[root] else {
+      return Err(Error::new("`root` should wait on two monkeys"));
+    };
+    let path = match data.path(root, humn, 0)? {
+      Some(path) if path.len() > 1 => path,
+      _ => return Err(Error::new("`root` doesn't wait on `humn`")),
+    };
+
+    // Both sides of `root` are equal, so undo every operation on the way down
+    // to `humn` on the value of the side without it.
+    let other = if path[1] == left { right } else { left };
+    let mut value = data.eval(other, 0)?;
+    for step in path[1..].windows(2) {
+      let (monkey, next) = (step[0], step[1]);
+      let Job::Operation(a, operator, b) = data.jobs[monkey] else {
+        unreachable!("only operations lead to `humn`");
+      };
+      let solved = if next == a {
+        operator.solve_left(data.eval(b, 0)?, value)
+      } else {
+        operator.solve_right(data.eval(a, 0)?, value)
+      };
+      value = solved.ok_or_else(|| Error::new("there is no whole number `humn` can yell"))?;
+    }
+    return Ok(value);
+  }
+}
+
+struct Monkeys {
+  names: Vec<String>,
+  /// Monkeys refer to others by their index.
+  jobs: Vec<Job>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Job {
+  Number(i64),
+  Operation(usize, Operator, usize),
+}
+
+impl Monkeys {
+  fn find(&self, name: &str) -> Result<usize> {
+    return self
+      .names
+      .iter()
+      .position(|n| n == name)
+      .ok_or_else(|| Error::new(format!("there is no monkey `{name}`")));
+  }
+
+  /// A monkey deeper than the number of monkeys must be waiting on itself.
+  fn check_depth(&self, monkey: usize, depth: usize) -> Result<()> {
+    if depth > self.jobs.len() {
+      let name = &self.names[monkey];
+      return Err(Error::new(format!("`{name}` ends up waiting on itself")));
+    }
+    return Ok(());
+  }
+
+  fn eval(&self, monkey: usize, depth: usize) -> Result<i64> {
+    self.check_depth(monkey, depth)?;
+    return match self.jobs[monkey] {
+      Job::Number(n) => Ok(n),
+      Job::Operation(a, operator, b) => {
+        let (a, b) = (self.eval(a, depth + 1)?, self.eval(b, depth + 1)?);
+        operator
+          .apply(a, b)
+          .ok_or_else(|| Error::new(format!("`{}` divides by 0", self.names[monkey])))
+      }
+    };
+  }
+
+  /// The monkeys from `monkey` down to `target`, when it waits on it.
+  fn path(&self, monkey: usize, target: usize, depth: usize) -> Result<Option<Vec<usize>>> {
+    self.check_depth(monkey, depth)?;
+    if monkey == target {
+      return Ok(Some(vec![monkey]));
+    }
+    let Job::Operation(a, _, b) = self.jobs[monkey] else {
+      return Ok(None);
+    };
+    let found = match self.path(a, target, depth + 1)? {
+      Some(path) => Some(path),
+      None => self.path(b, target, depth + 1)?,
+    };
+    return Ok(found.map(|path| [vec![monkey], path].concat()));
+  }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Operator {
+  Add,
+  Subtract,
+  Multiply,
+  Divide,
+}
+
+impl Operator {
+  /// `None` when dividing by 0.
+  fn apply(&self, a: i64, b: i64) -> Option<i64> {
+    return match self {
+      Operator::Add => Some(a + b),
+      Operator::Subtract => Some(a - b),
+      Operator::Multiply => Some(a * b),
+      Operator::Divide => a.checked_div(b),
+    };
+  }
+
+  /// `x` such that `x op known = result`, `None` when it isn't a whole number.
+  fn solve_left(&self, known: i64, result: i64) -> Option<i64> {
+    return match self {
+      Operator::Add => Some(result - known),
+      Operator::Subtract => Some(result + known),
+      Operator::Multiply => exact_div(result, known),
+      Operator::Divide => Some(result * known),
+    };
+  }
+
+  /// `x` such that `known op x = result`, `None` when it isn't a whole number.
+  fn solve_right(&self, known: i64, result: i64) -> Option<i64> {
+    return match self {
+      Operator::Add => Some(result - known),
+      Operator::Subtract => Some(known - result),
+      Operator::Multiply => exact_div(result, known),
+      Operator::Divide => exact_div(known, result),
+    };
+  }
+}
+
+fn exact_div(a: i64, b: i64) -> Option<i64> {
+  return (b != 0 && a % b == 0).then(|| a / b);
+}
@@ -0,0 +1,349 @@
+use std::{collections::HashSet, fmt::Display};
+
+use crate::{self as aoc, Direction, Error, Grid, Line, Lines, Point2, Point3, Result};
+
+pub fn solution() -> Box<dyn aoc::Solution> {
+  return aoc::solve(Day22);
+}
+
+struct Day22;
+
+impl aoc::Solver for Day22 {
+  type Input = (Board, Vec<Move>);
+  type Output1 = i64;
+  type Output2 = i64;
+
+  fn parse(&self, input: Lines) -> Result<(Board, Vec<Move>)> {
+    let groups = input.groups().collect::<Result<Vec<Vec<Line>>>>()?;
+    let [map, path] = &groups[..] else {
+      return Err(Error::new(
+        "expected the map and the path separated by a blank line",
+      ));
+    };
+    let [path] = &path[..] else {
+      return Err(path[1].error_at(1, "expected the path in a single line"));
+    };
+    return Ok((Board::parse(map)?, parse_path(path)?));
+  }
+
+  fn part1(&self, (board, path): &(Board, Vec<Move>)) -> Result<i64> {
+    return Ok(walk(board, path, |p, facing| board.wrap(p, facing)));
+  }
+
+  fn part2(&self, (board, path): &(Board, Vec<Move>)) -> Result<i64> {
+    let cube = Cube::fold(board)?;
+    return Ok(walk(board, path, |p, facing| cube.wrap(p, facing)));
+  }
+}
+
+type Point = Point2<i64>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Tile {
+  /// Not part of the map.
+  Void,
+  Open,
+  Wall,
+}
+
+struct Board {
+  tiles: Grid<Tile>,
+  /// Where the rows come from, to point at the tiles that can't be folded.
+  lines: Vec<Line>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Move {
+  Forward(usize),
+  Left,
+  Right,
+}
+
+impl Board {
+  /// Rows can be shorter than the widest one, what's missing is `Tile::Void`.
+  fn parse(lines: &[Line]) -> Result<Self> {
+    let width = lines.iter().map(|line| line.chars().count()).max().unwrap();
+    let mut cells = vec![];
+    for line in lines {
+      for (i, c) in line.char_indices() {
+        cells.push(match c {
+          ' ' => Tile::Void,
+          '.' => Tile::Open,
+          '#' => Tile::Wall,
+          _ => return Err(line.error(&line[i..], format!("unexpected `{c}`"))),
+        });
+      }
+      cells.resize(cells.len() + width - line.chars().count(), Tile::Void);
+    }
+    return Ok(Board {
+      tiles: Grid::from_vec(width, cells),
+      lines: lines.to_vec(),
+    });
+  }
+
+  /// Error pointing at `p`, or past the last row when it's below the map.
+  fn error(&self, p: Point, message: impl Display) -> Error {
+    return match self.lines.get(p.y as usize) {
+      Some(line) => line.error_at(p.x as usize + 1, message),
+      None => Error {
+        line: self.lines[self.lines.len() - 1].number + 1,
+        ..Error::new(message)
+      },
+    };
+  }
+
+  fn tile(&self, p: Point) -> Tile {
+    if p.x < 0 || p.y < 0 {
+      return Tile::Void;
+    }
+    return self
+      .tiles
+      .get((p.x as usize, p.y as usize))
+      .copied()
+      .unwrap_or(Tile::Void);
+  }
+
+  /// The leftmost open tile of the top row.
+  fn start(&self) -> Point {
+    let x = self
+      .tiles
+      .row(0)
+      .iter()
+      .position(|&t| t == Tile::Open)
+      .unwrap_or(0);
+    return Point::new(x as i64, 0);
+  }
+
+  /// Where walking off the map from `p` comes back on the opposite side.
+  fn wrap(&self, p: Point, facing: Direction) -> (Point, Direction) {
+    let back = facing.reverse();
+    let mut p = p;
+    while self.tile(p.step(back)) != Tile::Void {
+      p = p.step(back);
+    }
+    return (p, facing);
+  }
+}
+
+fn parse_path(line: &Line) -> Result<Vec<Move>> {
+  let mut path = vec![];
+  let mut rest: &str = line;
+  while !rest.is_empty() {
+    let digits = rest
+      .find(|c: char| !c.is_ascii_digit())
+      .unwrap_or(rest.len());
+    let (token, next) = match digits {
+      0 => rest.split_at(rest.chars().next().unwrap().len_utf8()),
+      _ => rest.split_at(digits),
+    };
+    path.push(match token {
+      "L" => Move::Left,
+      "R" => Move::Right,
+      _ if digits > 0 => Move::Forward(line.parse(token)?),
+      _ => return Err(line.error(token, "expected `L`, `R` or a number")),
+    });
+    rest = next;
+  }
+  return Ok(path);
+}
+
+/// Follows the `path` from the start, calling `wrap` to know where walking off
+/// the map leads. Returns the password.
+fn walk(
+  board: &Board,
+  path: &[Move],
+  wrap: impl Fn(Point, Direction) -> (Point, Direction),
+) -> i64 {
+  let (mut p, mut facing) = (board.start(), Direction::Right);
+  for &m in path {
+    let steps = match m {
+      Move::Left => {
+        facing = facing.turn_left();
+        continue;
+      }
+      Move::Right => {
+        facing = facing.turn_right();
+        continue;
+      }
+      Move::Forward(steps) => steps,
+    };
+    for _ in 0..steps {
+      let next = match p.step(facing) {
+        next if board.tile(next) == Tile::Void => wrap(p, facing),
+        next => (next, facing),
+      };
+      if board.tile(next.0) == Tile::Wall {
+        break;
+      }
+      (p, facing) = next;
+    }
+  }
+
+  let facing = match facing {
+    Direction::Right => 0,
+    Direction::Down => 1,
+    Direction::Left => 2,
+    Direction::Up => 3,
+  };
+  return 1000 * (p.y + 1) + 4 * (p.x + 1) + facing;
+}
+
+type Vector = Point3<i64>;
+
+/// A face of the net placed on the cube: `right` and `down` are where its `x`
+/// and `y` go in 3D, and `normal` points out of the cube.
+#[derive(Clone, Copy, Debug)]
+struct Face {
+  /// Top-left tile on the board.
+  corner: Point,
+  normal: Vector,
+  right: Vector,
+  down: Vector,
+}
+
+impl Face {
+  /// Where `direction` on the board points to in 3D.
+  fn towards(&self, direction: Direction) -> Vector {
+    return match direction {
+      Direction::Up => -self.down,
+      Direction::Right => self.right,
+      Direction::Down => self.down,
+      Direction::Left => -self.right,
+    };
+  }
+
+  /// The face next to this one on the net in `direction`, folded along their
+  /// shared edge.
+  fn fold(&self, direction: Direction, size: i64) -> Face {
+    let corner = self.corner + Point::new(0, 0).step(direction) * size;
+    let mut face = Face {
+      corner,
+      normal: self.towards(direction),
+      ..*self
+    };
+    // The axis of the edge stays, the other one now goes into the cube.
+    match direction {
+      Direction::Right => face.right = -self.normal,
+      Direction::Left => face.right = self.normal,
+      Direction::Down => face.down = -self.normal,
+      Direction::Up => face.down = self.normal,
+    }
+    return face;
+  }
+}
+
+struct Cube {
+  size: i64,
+  faces: Vec<Face>,
+}
+
+impl Cube {
+  /// Folds the net of the board, whichever of its 11 shapes it has. Each face
+  /// must be a full square of tiles, starting at a multiple of its size.
+  fn fold(board: &Board) -> Result<Self> {
+    let area = board.tiles.iter().filter(|&&t| t != Tile::Void).count() as i64;
+    let size = (1..).find(|size| 6 * size * size >= area).unwrap();
+    if 6 * size * size != area {
+      return Err(Error::new(format!(
+        "the map can't be folded into a cube, it has {area} tiles"
+      )));
+    }
+
+    let left = board
+      .tiles
+      .row(0)
+      .iter()
+      .position(|&t| t != Tile::Void)
+      .ok_or_else(|| board.error(Point::new(0, 0), "expected a face in the top row"))?;
+    let first = Face {
+      corner: Point::new(left as i64, 0),
+      normal: Vector::new(0, 0, 1),
+      right: Vector::new(1, 0, 0),
+      down: Vector::new(0, 1, 0),
+    };
+    if first.corner.x % size != 0 {
+      return Err(board.error(
+        first.corner,
+        format!("faces of {size}x{size} tiles should start at a multiple of {size}"),
+      ));
+    }
+
+    let mut faces = vec![];
+    let mut pending = vec![first];
+    while let Some(face) = pending.pop() {
+      if faces.iter().any(|f: &Face| f.corner == face.corner) {
+        continue;
+      }
+      let mut tiles =
+        (0..size).flat_map(|y| (0..size).map(move |x| face.corner + Point::new(x, y)));
+      if let Some(void) = tiles.find(|&p| board.tile(p) == Tile::Void) {
+        return Err(board.error(
+          void,
+          format!("expected a tile, faces should be {size}x{size} squares"),
+        ));
+      }
+      faces.push(face);
+      for direction in Direction::ALL {
+        let next = face.fold(direction, size);
+        if board.tile(next.corner) != Tile::Void {
+          pending.push(next);
+        }
+      }
+    }
+
+    let cube = Cube { size, faces };
+    let stray = board
+      .tiles
+      .positions()
+      .map(|(x, y)| Point::new(x as i64, y as i64))
+      .find(|&p| board.tile(p) != Tile::Void && cube.face(p).is_none());
+    if let Some(p) = stray {
+      return Err(board.error(p, "the tile isn't part of any face of the cube"));
+    }
+    let normals = cube.faces.iter().map(|f| f.normal).collect::<HashSet<_>>();
+    if cube.faces.len() != 6 || normals.len() != 6 {
+      return Err(Error::new("the faces of the map overlap when folded"));
+    }
+    return Ok(cube);
+  }
+
+  /// The face `p` is on.
+  fn face(&self, p: Point) -> Option<&Face> {
+    return self.faces.iter().find(|f| {
+      let local = p - f.corner;
+      return (0..self.size).contains(&local.x) && (0..self.size).contains(&local.y);
+    });
+  }
+
+  /// Walking off an edge of a face leads to the face that is past that edge on
+  /// the cube, facing away from the edge they share.
+  fn wrap(&self, p: Point, facing: Direction) -> (Point, Direction) {
+    let last = self.size - 1;
+    // `fold` made sure every tile is on a face.
+    let from = self.face(p).unwrap();
+    let local = p - from.corner;
+
+    // Going clockwise around each face, two faces go through the edge they
+    // share in opposite ways.
+    let along = match facing {
+      Direction::Up => local.x,
+      Direction::Right => local.y,
+      Direction::Down => last - local.x,
+      Direction::Left => last - local.y,
+    };
+    let normal = from.towards(facing);
+    let to = self.faces.iter().find(|f| f.normal == normal).unwrap();
+    let edge = Direction::ALL
+      .into_iter()
+      .find(|&d| to.towards(d) == from.normal)
+      .unwrap();
+    let along = last - along;
+    let local = match edge {
+      Direction::Up => Point::new(along, 0),
+      Direction::Right => Point::new(last, along),
+      Direction::Down => Point::new(last - along, last),
+      Direction::Left => Point::new(0, last - along),
+    };
+    return (to.corner + local, edge.reverse());
+  }
+}
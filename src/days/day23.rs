@@ -0,0 +1,161 @@
+use std::fmt::Display;
+
+use crate::{self as aoc, Bounds2, Error, Grid, Lines, Point2, Result};
+
+pub fn solution() -> Box<dyn aoc::Solution> {
+  return aoc::solve(Day23);
+}
+
+struct Day23;
+
+impl aoc::Solver for Day23 {
+  type Input = Elves;
+  type Output1 = usize;
+  type Output2 = usize;
+
+  fn parse(&self, input: Lines) -> Result<Elves> {
+    let lines = input.all()?;
+    let grid = Grid::parse(&lines, |c| match c {
+      '#' => Some(true),
+      '.' => Some(false),
+      _ => None,
+    })?;
+    let elves = grid
+      .positions()
+      .filter(|&p| grid[p])
+      .map(|(x, y)| Point::new(x, y))
+      .collect::<Vec<_>>();
+    if elves.is_empty() {
+      return Err(Error::new("there are no elves"));
+    }
+    return Ok(Elves {
+      grid,
+      elves,
+      round: 0,
+    });
+  }
+
+  fn part1(&self, data: &Elves) -> Result<usize> {
+    let mut elves = data.clone();
+    for _ in 0..10 {
+      elves.round();
+    }
+    let size = elves.bounds().size();
+    return Ok(size.x * size.y - elves.elves.len());
+  }
+
+  fn part2(&self, data: &Elves) -> Result<usize> {
+    let mut elves = data.clone();
+    while elves.round() {}
+    return Ok(elves.round);
+  }
+}
+
+type Point = Point2<usize>;
+
+/// The tile an elf moves to followed by the two diagonals next to it, which
+/// must be empty too. In the order of the first round: north, south, west and
+/// east.
+const CHECKS: [[(isize, isize); 3]; 4] = [
+  [(0, -1), (-1, -1), (1, -1)],
+  [(0, 1), (-1, 1), (1, 1)],
+  [(-1, 0), (-1, -1), (-1, 1)],
+  [(1, 0), (1, -1), (1, 1)],
+];
+
+#[derive(Clone)]
+struct Elves {
+  /// Where the elves are, always with an empty border so that looking around
+  /// never goes out of it.
+  grid: Grid<bool>,
+  elves: Vec<Point>,
+  /// Rounds done so far.
+  round: usize,
+}
+
+impl Elves {
+  fn bounds(&self) -> Bounds2<usize> {
+    return Bounds2::from_points(self.elves.iter().copied()).unwrap();
+  }
+
+  fn occupied(&self, p: Point, (dx, dy): (isize, isize)) -> bool {
+    return self.grid[(p.x.wrapping_add_signed(dx), p.y.wrapping_add_signed(dy))];
+  }
+
+  /// Moves the grid away from the elves once they reach its border.
+  fn make_room(&mut self) {
+    let bounds = self.bounds();
+    let (width, height) = (self.grid.width(), self.grid.height());
+    let inner = Bounds2 {
+      min: Point::new(1, 1),
+      max: Point::new(width.saturating_sub(2), height.saturating_sub(2)),
+    };
+    if inner.contains(&bounds.min) && inner.contains(&bounds.max) {
+      return;
+    }
+
+    let margin = width.max(height) / 2 + 1;
+    let mut grid = Grid::new(width + 2 * margin, height + 2 * margin, false);
+    for elf in self.elves.iter_mut() {
+      *elf += Point::new(margin, margin);
+      grid[*elf] = true;
+    }
+    self.grid = grid;
+  }
+
+  /// Returns whether any elf moved.
+  fn round(&mut self) -> bool {
+    self.make_room();
+    let proposals = self
+      .elves
+      .iter()
+      .map(|&elf| {
+        let alone = CHECKS.iter().flatten().all(|&d| !self.occupied(elf, d));
+        if alone {
+          return None;
+        }
+        return (0..4)
+          .map(|i| CHECKS[(self.round + i) % 4])
+          .find(|checks| checks.iter().all(|&d| !self.occupied(elf, d)))
+          .map(|[(dx, dy), ..]| {
+            Point::new(elf.x.wrapping_add_signed(dx), elf.y.wrapping_add_signed(dy))
+          });
+      })
+      .collect::<Vec<_>>();
+
+    let mut proposed = Grid::new(self.grid.width(), self.grid.height(), 0u8);
+    for &to in proposals.iter().flatten() {
+      proposed[to] += 1;
+    }
+
+    let mut moved = false;
+    for (elf, proposal) in self.elves.iter_mut().zip(proposals) {
+      match proposal {
+        Some(to) if proposed[to] == 1 => {
+          self.grid[*elf] = false;
+          self.grid[to] = true;
+          *elf = to;
+          moved = true;
+        }
+        _ => (),
+      }
+    }
+
+    self.round += 1;
+    return moved;
+  }
+}
+
+/// Prints the smallest rectangle holding all the elves, like the puzzle.
+impl Display for Elves {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let bounds = self.bounds();
+    for y in bounds.min.y..=bounds.max.y {
+      for x in bounds.min.x..=bounds.max.x {
+        write!(f, "{}", if self.grid[(x, y)] { '#' } else { '.' })?;
+      }
+      writeln!(f)?;
+    }
+    return Ok(());
+  }
+}
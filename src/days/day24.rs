@@ -0,0 +1,122 @@
+use crate::{self as aoc, Direction, Error, Grid, Lines, Result};
+
+pub fn solution() -> Box<dyn aoc::Solution> {
+  return aoc::solve(Day24);
+}
+
+struct Day24;
+
+impl aoc::Solver for Day24 {
+  type Input = Valley;
+  type Output1 = usize;
+  type Output2 = usize;
+
+  fn parse(&self, input: Lines) -> Result<Valley> {
+    let lines = input.all()?;
+    let map = Grid::parse(&lines, |c| match c {
+      '#' => Some(Tile::Wall),
+      '.' => Some(Tile::Open),
+      '^' => Some(Tile::Blizzard(Direction::Up)),
+      '>' => Some(Tile::Blizzard(Direction::Right)),
+      'v' => Some(Tile::Blizzard(Direction::Down)),
+      '<' => Some(Tile::Blizzard(Direction::Left)),
+      _ => None,
+    })?;
+    if map.width() < 3 || map.height() < 3 {
+      return Err(Error::new("expected a valley surrounded by walls"));
+    }
+
+    let gap = |y: usize, side: &str| -> Result<Point> {
+      let x = map.row(y).iter().position(|&t| t == Tile::Open);
+      let x = x.ok_or_else(|| Error::new(format!("there is no gap in the {side} wall")))?;
+      return Ok((x, y));
+    };
+    let start = gap(0, "top")?;
+    let goal = gap(map.height() - 1, "bottom")?;
+
+    let (width, height) = (map.width() - 2, map.height() - 2);
+    return Ok(Valley {
+      map,
+      start,
+      goal,
+      period: lcm(width, height),
+    });
+  }
+
+  fn part1(&self, data: &Valley) -> Result<usize> {
+    return data.cross(data.start, data.goal, 0);
+  }
+
+  fn part2(&self, data: &Valley) -> Result<usize> {
+    let there = data.cross(data.start, data.goal, 0)?;
+    let back = data.cross(data.goal, data.start, there)?;
+    return data.cross(data.start, data.goal, back);
+  }
+}
+
+type Point = (usize, usize);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Tile {
+  Wall,
+  Open,
+  /// Where a blizzard is at minute 0.
+  Blizzard(Direction),
+}
+
+struct Valley {
+  map: Grid<Tile>,
+  start: Point,
+  goal: Point,
+  /// Blizzards are back where they started every `period` minutes.
+  period: usize,
+}
+
+impl Valley {
+  /// Whether a blizzard is on `p` at `minute`. Each of them wraps around its
+  /// row or column, so it's enough to look for one that would reach `p` now.
+  fn blizzard(&self, (x, y): Point, minute: usize) -> bool {
+    let (width, height) = (self.map.width() - 2, self.map.height() - 2);
+    if !(1..=width).contains(&x) || !(1..=height).contains(&y) {
+      return false;
+    }
+    let (x, y) = (x - 1, y - 1);
+    let (dx, dy) = (minute % width, minute % height);
+    let from = |x: usize, y: usize| self.map[(x + 1, y + 1)];
+    return from((x + width - dx) % width, y) == Tile::Blizzard(Direction::Right)
+      || from((x + dx) % width, y) == Tile::Blizzard(Direction::Left)
+      || from(x, (y + height - dy) % height) == Tile::Blizzard(Direction::Down)
+      || from(x, (y + dy) % height) == Tile::Blizzard(Direction::Up);
+  }
+
+  /// The minute of arriving to `to` when leaving `from` at `minute`.
+  ///
+  /// Being at the same tile at the same point of the blizzards' cycle is the
+  /// same, so the search is over `(tile, minute % period)`.
+  fn cross(&self, from: Point, to: Point, minute: usize) -> Result<usize> {
+    let route = aoc::bfs(
+      [(from, minute % self.period)],
+      |&(p, time)| {
+        let time = (time + 1) % self.period;
+        return [p]
+          .into_iter()
+          .chain(self.map.neighbours4(p))
+          .filter(move |&next| self.map[next] != Tile::Wall && !self.blizzard(next, time))
+          .map(move |next| (next, time));
+      },
+      |&(p, _)| p == to,
+    );
+    let route = route.ok_or_else(|| Error::new("the blizzards never let you through"))?;
+    return Ok(minute + route.cost);
+  }
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+  let gcd = |mut a: usize, mut b: usize| {
+    while b != 0 {
+      (a, b) = (b, a % b);
+    }
+    return a;
+  };
+  return a / gcd(a, b) * b;
+}
@@ -0,0 +1,67 @@
+use crate::{self as aoc, Alphabet, CharMask, Error, Letters, Lines, Result};
+
+pub fn solution() -> Box<dyn aoc::Solution> {
+  return aoc::solve(Day3);
+}
+
+struct Day3;
+
+impl aoc::Solver for Day3 {
+  type Input = Vec<Vec<char>>;
+  type Output1 = usize;
+  type Output2 = usize;
+
+  fn parse(&self, input: Lines) -> Result<Vec<Vec<char>>> {
+    let lines = input.all()?;
+    return lines
+      .iter()
+      .map(|line| {
+        match line
+          .char_indices()
+          .find(|&(_, c)| Letters::index(c).is_none())
+        {
+          Some((i, c)) => Err(line.error(&line[i..], format!("unexpected `{c}`"))),
+          None => Ok(line.chars().collect()),
+        }
+      })
+      .collect();
+  }
+
+  fn part1(&self, data: &Vec<Vec<char>>) -> Result<usize> {
+    return data
+      .iter()
+      .map(|line| {
+        let mid = line.len() / 2;
+        let (left, right) = line.split_at(mid);
+        let a = mask(left)?;
+        let b = mask(right)?;
+
+        return Ok(priority(a & b));
+      })
+      .sum();
+  }
+
+  fn part2(&self, data: &Vec<Vec<char>>) -> Result<usize> {
+    if !data.len().is_multiple_of(3) {
+      return Err(Error::new("the elves should come in groups of three"));
+    }
+    return data
+      .chunks(3)
+      .map(|chunk| {
+        let [a, b, c] = [mask(&chunk[0])?, mask(&chunk[1])?, mask(&chunk[2])?];
+
+        return Ok(priority(a & b & c));
+      })
+      .sum();
+  }
+}
+
+fn mask(items: &[char]) -> Result<CharMask> {
+  return CharMask::try_from_iter(items.iter().copied())
+    .map_err(|c| Error::new(format!("unexpected `{c}`")));
+}
+
+/// `Letters` are indexed by priority.
+fn priority(shared: CharMask) -> usize {
+  return shared.first().and_then(Letters::index).unwrap_or(0);
+}
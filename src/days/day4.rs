@@ -0,0 +1,75 @@
+use std::cmp;
+
+use crate::{self as aoc, Line, Lines, Result};
+
+pub fn solution() -> Box<dyn aoc::Solution> {
+  return aoc::solve(Day4);
+}
+
+struct Day4;
+
+impl aoc::Solver for Day4 {
+  type Input = Vec<(Range, Range)>;
+  type Output1 = usize;
+  type Output2 = usize;
+
+  fn parse(&self, input: Lines) -> Result<Vec<(Range, Range)>> {
+    let lines = input.all()?;
+    return lines
+      .iter()
+      .map(|line| {
+        let (a, b) = line
+          .split_once(',')
+          .ok_or_else(|| line.error(line, "expected two ranges separated by `,`"))?;
+        return Ok((Range::parse(line, a)?, Range::parse(line, b)?));
+      })
+      .collect();
+  }
+
+  fn part1(&self, data: &Vec<(Range, Range)>) -> Result<usize> {
+    return Ok(
+      data
+        .iter()
+        .filter(|(a, b)| match &a.overlap(b) {
+          None => return false,
+          Some(overlap) => return overlap == a || overlap == b,
+        })
+        .count(),
+    );
+  }
+
+  fn part2(&self, data: &Vec<(Range, Range)>) -> Result<usize> {
+    return Ok(data.iter().filter(|(a, b)| a.overlap(b).is_some()).count());
+  }
+}
+
+#[derive(Clone, PartialEq)]
+struct Range {
+  start: u32,
+  end: u32,
+}
+
+impl Range {
+  /// Parses `START-END`, which is a slice of `line`.
+  fn parse(line: &Line, s: &str) -> Result<Self> {
+    let (start, end) = s
+      .split_once('-')
+      .ok_or_else(|| line.error(s, "expected a range like `2-4`"))?;
+    return Ok(Range {
+      start: line.parse(start)?,
+      end: line.parse(end)?,
+    });
+  }
+
+  fn overlap(&self, other: &Range) -> Option<Range> {
+    let range = Range {
+      start: cmp::max(self.start, other.start),
+      end: cmp::min(self.end, other.end),
+    };
+    return if range.start > range.end {
+      None
+    } else {
+      Some(range)
+    };
+  }
+}
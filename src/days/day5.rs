@@ -0,0 +1,125 @@
+use std::collections::LinkedList;
+
+use crate::{self as aoc, Error, Line, Lines, Result};
+
+pub fn solution() -> Box<dyn aoc::Solution> {
+  return aoc::solve(Day5);
+}
+
+struct Day5;
+
+impl aoc::Solver for Day5 {
+  type Input = (Vec<LinkedList<char>>, Vec<Action>);
+  type Output1 = String;
+  type Output2 = String;
+
+  fn parse(&self, mut input: Lines) -> Result<(Vec<LinkedList<char>>, Vec<Action>)> {
+    let is_empty = |line: &Result<Line>| matches!(line, Ok(line) if line.is_empty());
+    let raw_stacks = input
+      .by_ref()
+      .take_while(|line| !is_empty(line))
+      .collect::<Result<Vec<Line>>>()?;
+    let mut raw_stacks = raw_stacks.iter().rev();
+    let count = raw_stacks
+      .next()
+      .ok_or_else(|| Error::new("the input is empty"))?
+      .chars()
+      .filter(|c| !c.is_whitespace())
+      .count();
+
+    let mut stacks = vec![LinkedList::new(); count];
+
+    for line in raw_stacks {
+      for (i, c) in line.chars().skip(1).step_by(4).enumerate() {
+        if c == ' ' {
+          continue;
+        }
+        if i >= count {
+          return Err(line.error_at(4 * i + 2, format!("there are only {count} stacks")));
+        }
+        stacks[i].push_back(c)
+      }
+    }
+
+    // There could be millions of steps, no need to keep their lines around.
+    let steps = input
+      .filter(|line| !is_empty(line))
+      .map(|line| Action::parse(&line?, count))
+      .collect::<Result<Vec<Action>>>()?;
+    if steps.is_empty() {
+      return Err(Error::new("expected the steps after an empty line"));
+    }
+
+    return Ok((stacks, steps));
+  }
+
+  fn part1(&self, data: &(Vec<LinkedList<char>>, Vec<Action>)) -> Result<String> {
+    return Ok(move_stacks(data, &|c| c.to_owned()));
+  }
+
+  fn part2(&self, data: &(Vec<LinkedList<char>>, Vec<Action>)) -> Result<String> {
+    return Ok(move_stacks(data, &|c| c.iter().rev().copied().collect()));
+  }
+}
+
+#[derive(Clone, Debug)]
+struct Action {
+  count: usize,
+  from: usize,
+  to: usize,
+}
+
+impl Action {
+  /// Parses `move COUNT from FROM to TO`, where the stacks are 1-based.
+  fn parse(line: &Line, stacks: usize) -> Result<Self> {
+    let mut numbers = line
+      .split_whitespace()
+      .filter_map(|part| Some((part, part.parse::<usize>().ok()?)));
+    let mut next = || {
+      numbers
+        .next()
+        .ok_or_else(|| line.error(line, "expected `move COUNT from FROM to TO`"))
+    };
+
+    let (_, count) = next()?;
+    let mut stack = || match next()? {
+      (_, n) if 1 <= n && n <= stacks => Ok(n - 1),
+      (part, _) => Err(line.error(part, format!("stacks go from 1 to {stacks}"))),
+    };
+
+    return Ok(Action {
+      count,
+      from: stack()?,
+      to: stack()?,
+    });
+  }
+}
+
+fn move_stacks(
+  (stacks, steps): &(Vec<LinkedList<char>>, Vec<Action>),
+  transform: &dyn Fn(&LinkedList<char>) -> LinkedList<char>,
+) -> String {
+  let mut stacks = stacks.clone();
+  for action in steps {
+    if stacks[action.from].is_empty() {
+      continue;
+    };
+
+    let removed: LinkedList<char> = stacks[action.from]
+      .iter()
+      .rev()
+      .take(action.count)
+      .copied()
+      .collect();
+
+    for v in transform(&removed) {
+      stacks[action.from].pop_back();
+      stacks[action.to].push_back(v);
+    }
+  }
+
+  return stacks
+    .iter()
+    .map(|stack| stack.back().unwrap_or(&' '))
+    .collect();
+}
@@ -0,0 +1,47 @@
+use crate::{self as aoc, Alphabet, CharMask, Error, Letters, Lines, Result};
+
+pub fn solution() -> Box<dyn aoc::Solution> {
+  return aoc::solve(Day6);
+}
+
+struct Day6;
+
+impl aoc::Solver for Day6 {
+  type Input = String;
+  type Output1 = usize;
+  type Output2 = usize;
+
+  fn parse(&self, input: Lines) -> Result<String> {
+    let lines = input.all()?;
+    let line = lines
+      .first()
+      .ok_or_else(|| Error::new("the input is empty"))?;
+    if let Some((i, c)) = line
+      .char_indices()
+      .find(|&(_, c)| Letters::index(c).is_none())
+    {
+      return Err(line.error(&line[i..], format!("unexpected `{c}`")));
+    }
+    return Ok(line.to_string());
+  }
+
+  fn part1(&self, data: &String) -> Result<usize> {
+    return find(data, 4);
+  }
+
+  fn part2(&self, data: &String) -> Result<usize> {
+    return find(data, 14);
+  }
+}
+
+fn find(data: &str, n: usize) -> Result<usize> {
+  let chars = data.chars().collect::<Vec<char>>();
+  for (i, window) in chars.windows(n).enumerate() {
+    let mask = CharMask::<Letters>::try_from_iter(window.iter().copied())
+      .map_err(|c| Error::new(format!("unexpected `{c}`")))?;
+    if mask.len() == n {
+      return Ok(i + n);
+    }
+  }
+  return Err(Error::new(format!("no {n} different characters in a row")));
+}
@@ -0,0 +1,132 @@
+use crate::{self as aoc, Error, Lines, Result};
+
+pub fn solution() -> Box<dyn aoc::Solution> {
+  return aoc::solve(Day7);
+}
+
+struct Day7;
+
+impl aoc::Solver for Day7 {
+  type Input = Vec<FsKind>;
+  type Output1 = usize;
+  type Output2 = usize;
+
+  fn parse(&self, input: Lines) -> Result<Vec<FsKind>> {
+    let lines = input.all()?;
+    let mut cwd: Vec<String> = vec![];
+    let mut fs: Vec<FsKind> = vec![FsKind::Dir("/".to_string(), None)];
+
+    for line in lines {
+      if line.starts_with("$") {
+        let [command] = line.scan("$ {}")?;
+        match command.split_once(' ') {
+          Some(("cd", "..")) => {
+            cwd.pop();
+          }
+          Some(("cd", "/")) => cwd.truncate(0),
+          Some(("cd", name)) if !name.is_empty() => cwd.push(name.to_string()),
+          // $ ls doesn't need to be handled.
+          None if command == "ls" => (),
+          _ => return Err(line.error(command, "expected `cd DIR` or `ls`")),
+        }
+      } else {
+        let mut parts = line.splitn(2, " ");
+        let size_or_dir = parts.next().unwrap();
+        let name = line.expect(parts.next(), "a name after the size or `dir`")?;
+        let full_name = if cwd.is_empty() {
+          format!("/{name}")
+        } else {
+          let cwd = cwd.join("/");
+          format!("/{cwd}/{name}")
+        };
+
+        if size_or_dir.starts_with("d") {
+          fs.push(FsKind::Dir(full_name + "/", None));
+        } else {
+          fs.push(FsKind::File(full_name, line.parse(size_or_dir)?));
+        }
+      }
+    }
+
+    fs.sort_unstable_by_key(|v| v.name());
+    update_dir_size(&mut fs, 0);
+
+    return Ok(fs);
+  }
+
+  fn part1(&self, data: &Vec<FsKind>) -> Result<usize> {
+    return Ok(
+      data
+        .iter()
+        .filter_map(|v| match v {
+          FsKind::Dir(_, Some(size)) if *size <= 100_000 => Some(size),
+          _ => None,
+        })
+        .sum(),
+    );
+  }
+
+  fn part2(&self, data: &Vec<FsKind>) -> Result<usize> {
+    const FS_SPACE: usize = 70_000_000;
+    const NEED: usize = 30_000_000;
+    let have = FS_SPACE.saturating_sub(data[0].size());
+    let want = NEED.saturating_sub(have);
+
+    return data
+      .iter()
+      .filter_map(|v| match v {
+        FsKind::Dir(_, Some(size)) if *size >= want => Some(*size),
+        _ => None,
+      })
+      .min()
+      .ok_or_else(|| Error::new("no directory is big enough"));
+  }
+}
+
+#[derive(Clone, Debug)]
+enum FsKind {
+  Dir(String, Option<usize>),
+  File(String, usize),
+}
+
+impl FsKind {
+  fn name(&self) -> String {
+    match self {
+      FsKind::Dir(name, _) => name.to_string(),
+      FsKind::File(name, _) => name.to_string(),
+    }
+  }
+  fn size(&self) -> usize {
+    match self {
+      FsKind::Dir(_, Some(size)) => *size,
+      FsKind::File(_, size) => *size,
+      _ => panic!("impossible"),
+    }
+  }
+}
+
+fn update_dir_size(fs: &mut Vec<FsKind>, current_index: usize) -> usize {
+  let prefix = fs[current_index].name();
+  let mut total_size = 0;
+  let mut i = current_index + 1;
+  let mut last_index = fs.len();
+  while i < last_index {
+    if !fs[i].name().starts_with(&prefix) {
+      last_index = i;
+      continue;
+    }
+    match fs[i] {
+      FsKind::Dir(_, _) => {
+        let next_i = update_dir_size(fs, i);
+        total_size += fs[i].size();
+        i = next_i;
+      }
+      FsKind::File(_, size) => {
+        total_size += size;
+        i += 1;
+      }
+    }
+  }
+  fs[current_index] = FsKind::Dir(prefix, Some(total_size));
+  return last_index;
+}
@@ -0,0 +1,125 @@
+use crate::{self as aoc, Grid, Lines, Result};
+
+pub fn solution() -> Box<dyn aoc::Solution> {
+  return aoc::solve(Day8);
+}
+
+struct Day8;
+
+impl aoc::Solver for Day8 {
+  type Input = Grid<u8>;
+  type Output1 = usize;
+  type Output2 = usize;
+
+  fn parse(&self, input: Lines) -> Result<Grid<u8>> {
+    let lines = input.all()?;
+    // Offset by 1 so the trees go from 1 to 10. This allows the "unknown height" for a
+    // tree to be 0, that way we don't need signed integers.
+    return Grid::parse(&lines, |c| Some(c.to_digit(10)? as u8 + 1));
+  }
+
+  fn part1(&self, data: &Grid<u8>) -> Result<usize> {
+    return Ok(p1(data));
+  }
+
+  fn part2(&self, data: &Grid<u8>) -> Result<usize> {
+    return Ok(p2(data));
+  }
+}
+
+/// Walk from one edge to another until the next element is too tall.
+///
+/// It walks each row and column from both ends keeping track of the largest
+/// element so far, every tree taller than that is seen.
+fn p1(data: &Grid<u8>) -> usize {
+  let mut seen = data.map(|_| false);
+  let (width, height) = (data.width(), data.height());
+
+  let mut walk = |line: &mut dyn Iterator<Item = (usize, usize)>| {
+    let mut last = 0;
+    for p in line {
+      if data[p] > last {
+        last = data[p];
+        seen[p] = true;
+      }
+    }
+  };
+  for y in 0..height {
+    walk(&mut (0..width).map(|x| (x, y)));
+    walk(&mut (0..width).rev().map(|x| (x, y)));
+  }
+  for x in 0..width {
+    walk(&mut (0..height).map(|y| (x, y)));
+    walk(&mut (0..height).rev().map(|y| (x, y)));
+  }
+
+  return seen.iter().filter(|&&seen| seen).count();
+}
+
+/// TODO: Use DP instead of bruteforce. For example each cell could keep track
+/// of each direction "how many are shorter than me". Then on the lookup phase
+/// hypothetically could be something like..
+///
+/// ```text
+/// let mut right = 1;
+/// while right < scenic[i].len() {
+///   if data[i][j+right] >= height { break; }
+///   right += scenic[i][j+right].right
+/// }
+/// ```
+///
+/// Basically a cell will answer how many can be seen from there so instead of
+/// checking each of the following cells, we can jump straight to the next cell
+/// that can't be seen by it. We repeat while the next cell is shorter.
+fn p2(data: &Grid<u8>) -> usize {
+  let mut scenic = data.map(|_| 1);
+  let (width, height) = (data.width(), data.height());
+
+  // The trees on the edges see nothing in some direction, and the last tree
+  // of each direction is always seen, so only the ones in between are walked.
+  for y in 1..height.saturating_sub(1) {
+    let row = data.row(y);
+    for x in 1..width.saturating_sub(1) {
+      let height_here = data[(x, y)];
+      let shorter = |v: &&u8| **v < height_here;
+
+      let left = row[1..x].iter().rev().take_while(shorter).count();
+      let right = row[x + 1..width - 1].iter().take_while(shorter).count();
+      let up = data
+        .column(x)
+        .take(y)
+        .skip(1)
+        .rev()
+        .take_while(shorter)
+        .count();
+      let down = data
+        .column(x)
+        .skip(y + 1)
+        .take(height - y - 2)
+        .take_while(shorter)
+        .count();
+
+      scenic[(x, y)] = (left + 1) * (right + 1) * (up + 1) * (down + 1);
+    }
+  }
+
+  return scenic.iter().max().unwrap().to_owned();
+}
+
+fn _print_seen(data: &Grid<u8>, seen: &Grid<bool>) {
+  for (p, height) in data.enumerate() {
+    match seen[p] {
+      true => print!("."),
+      false => print!("{height}"),
+    }
+    if p.0 == data.width() - 1 {
+      println!();
+    }
+  }
+}
+
+fn _print_scenic(scenic: &Grid<usize>) {
+  for row in scenic.rows() {
+    println!("{row:?}");
+  }
+}
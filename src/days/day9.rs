@@ -0,0 +1,82 @@
+use std::collections::HashSet;
+
+use crate::{self as aoc, Direction, Lines, Point2, Result};
+
+pub fn solution() -> Box<dyn aoc::Solution> {
+  return aoc::solve(Day9);
+}
+
+struct Day9;
+
+impl aoc::Solver for Day9 {
+  type Input = Vec<(Direction, u8)>;
+  type Output1 = usize;
+  type Output2 = usize;
+
+  fn parse(&self, input: Lines) -> Result<Vec<(Direction, u8)>> {
+    let lines = input.all()?;
+    return lines
+      .iter()
+      .map(|line| {
+        let (dir, number) = line
+          .split_once(' ')
+          .ok_or_else(|| line.error_at(1, "expected a direction and a number"))?;
+        let number = line.parse(number)?;
+        return match dir {
+          "U" => Ok((Direction::Up, number)),
+          "D" => Ok((Direction::Down, number)),
+          "R" => Ok((Direction::Right, number)),
+          "L" => Ok((Direction::Left, number)),
+          _ => Err(line.error(
+            dir,
+            format!("invalid direction `{dir}`, expected U, D, L or R"),
+          )),
+        };
+      })
+      .collect();
+  }
+
+  fn part1(&self, data: &Vec<(Direction, u8)>) -> Result<usize> {
+    return Ok(snake(data, 2));
+  }
+
+  fn part2(&self, data: &Vec<(Direction, u8)>) -> Result<usize> {
+    return Ok(snake(data, 10));
+  }
+}
+
+type Point = Point2<i32>;
+
+fn best_move(knot: &Point, head: &Point) -> Point {
+  // Sometimes the best move is not to move.
+  if knot.chebyshev(head) <= 1 {
+    return *knot;
+  }
+
+  // Try out all possible moves, filter for whichever are actually possible,
+  // and then find the closest.
+  return knot
+    .neighbours8()
+    .into_iter()
+    .filter(|p| p.chebyshev(head) <= 1)
+    .min_by_key(|p| p.manhattan(head))
+    .unwrap();
+}
+
+fn snake(data: &[(Direction, u8)], length: usize) -> usize {
+  let mut snake = vec![Point::default(); length];
+  let mut seen: HashSet<Point> = HashSet::new();
+
+  for &(dir, steps) in data {
+    for _ in 0..steps {
+      snake[0] = snake[0].step(dir);
+      for i in 1..length {
+        snake[i] = best_move(&snake[i], &snake[i - 1]);
+      }
+
+      seen.insert(*snake.last().unwrap());
+    }
+  }
+
+  return seen.len();
+}
@@ -0,0 +1,75 @@
+use crate::Solution;
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+/// A puzzle known to the `aoc` runner.
+pub struct Day {
+  pub number: u8,
+  pub solution: fn() -> Box<dyn Solution>,
+}
+
+impl Day {
+  pub const fn new(number: u8, solution: fn() -> Box<dyn Solution>) -> Self {
+    return Day { number, solution };
+  }
+
+  /// Name of the day, also used as its directory under `data/`.
+  pub fn name(&self) -> String {
+    return format!("day{}", self.number);
+  }
+}
+
+/// Every registered day, sorted by number.
+pub const DAYS: &[Day] = &[
+  Day::new(1, day1::solution),
+  Day::new(2, day2::solution),
+  Day::new(3, day3::solution),
+  Day::new(4, day4::solution),
+  Day::new(5, day5::solution),
+  Day::new(6, day6::solution),
+  Day::new(7, day7::solution),
+  Day::new(8, day8::solution),
+  Day::new(9, day9::solution),
+  Day::new(10, day10::solution),
+  Day::new(11, day11::solution),
+  Day::new(12, day12::solution),
+  Day::new(13, day13::solution),
+  Day::new(14, day14::solution),
+  Day::new(15, day15::solution),
+  Day::new(16, day16::solution),
+  Day::new(17, day17::solution),
+  Day::new(18, day18::solution),
+  Day::new(19, day19::solution),
+  Day::new(20, day20::solution),
+  Day::new(21, day21::solution),
+  Day::new(22, day22::solution),
+  Day::new(23, day23::solution),
+  Day::new(24, day24::solution),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+  return DAYS.iter().find(|day| day.number == number);
+}
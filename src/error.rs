@@ -0,0 +1,59 @@
+use std::fmt::Display;
+
+/// Something wrong with the puzzle input, pointing at where it happened.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error {
+  /// Set by the harness, readers don't know which file they are reading.
+  pub file: Option<String>,
+  /// 1-based, 0 when the error isn't about a particular line.
+  pub line: usize,
+  /// 1-based and counted in characters, 0 when it isn't about a particular column.
+  pub column: usize,
+  /// The offending line, shown under the message.
+  pub text: Option<String>,
+  pub message: String,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+  /// An error that isn't about a particular line, e.g. "no path found".
+  pub fn new(message: impl Display) -> Self {
+    return Error {
+      file: None,
+      line: 0,
+      column: 0,
+      text: None,
+      message: message.to_string(),
+    };
+  }
+
+  pub fn in_file(mut self, file: &str) -> Self {
+    self.file.get_or_insert(file.to_string());
+    return self;
+  }
+}
+
+impl Display for Error {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let location = match (&self.file, self.line, self.column) {
+      (Some(file), 0, _) => format!("{file}: "),
+      (Some(file), line, 0) => format!("{file}:{line}: "),
+      (Some(file), line, column) => format!("{file}:{line}:{column}: "),
+      (None, 0, _) => String::new(),
+      (None, line, 0) => format!("line {line}: "),
+      (None, line, column) => format!("line {line}, column {column}: "),
+    };
+    write!(f, "{location}{}", self.message)?;
+
+    if let Some(text) = &self.text {
+      write!(f, "\n  {text}")?;
+      if self.column > 0 {
+        write!(f, "\n  {}^", " ".repeat(self.column - 1))?;
+      }
+    }
+    return Ok(());
+  }
+}
+
+impl std::error::Error for Error {}
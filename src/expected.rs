@@ -0,0 +1,114 @@
+use std::{fs, io, path::Path};
+
+use crate::{Error, Part, Summary};
+
+/// Known answers of a dataset, stored next to it as `{name}.expected`:
+///
+/// ```text
+/// --- part 1
+/// 24000
+/// --- part 2
+/// 45000
+/// ```
+///
+/// Answers may span multiple lines, they run until the next `--- part` header.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Expected {
+  pub part1: Option<String>,
+  pub part2: Option<String>,
+}
+
+const HEADER: &str = "--- part ";
+
+impl Expected {
+  /// Returns the default (nothing known) when the file doesn't exist.
+  pub fn read(path: &Path) -> io::Result<Self> {
+    let content = match fs::read_to_string(path) {
+      Ok(content) => content,
+      Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+      Err(e) => return Err(e),
+    };
+
+    let mut expected = Self::default();
+    let mut current: Option<(&str, Vec<&str>)> = None;
+    for line in content.lines().chain([HEADER]) {
+      let Some(part) = line.strip_prefix(HEADER) else {
+        if let Some((_, answer)) = current.as_mut() {
+          answer.push(line);
+        }
+        continue;
+      };
+
+      if let Some((part, answer)) = current.take() {
+        let answer = Some(answer.join("\n"));
+        match part {
+          "1" => expected.part1 = answer,
+          "2" => expected.part2 = answer,
+          _ => {
+            let message = format!("{}: unknown part `{part}`", path.display());
+            return Err(io::Error::new(io::ErrorKind::InvalidData, message));
+          }
+        }
+      }
+      current = Some((part.trim(), vec![]));
+    }
+
+    return Ok(expected);
+  }
+
+  pub fn write(&self, path: &Path) -> io::Result<()> {
+    let content = [("1", &self.part1), ("2", &self.part2)]
+      .iter()
+      .filter_map(|(part, answer)| Some(format!("{HEADER}{part}\n{}\n", answer.as_ref()?)))
+      .collect::<String>();
+    return fs::write(path, content);
+  }
+
+  pub fn get(&self, part: Part) -> Option<&String> {
+    return match part {
+      Part::One => self.part1.as_ref(),
+      Part::Two => self.part2.as_ref(),
+    };
+  }
+
+  /// Keeps the known answers for the parts that didn't run.
+  pub fn update(&mut self, summary: &Summary) {
+    if let Some((Ok(answer), _)) = &summary.part1 {
+      self.part1 = Some(answer.clone());
+    }
+    if let Some((Ok(answer), _)) = &summary.part2 {
+      self.part2 = Some(answer.clone());
+    }
+  }
+
+  pub fn check(&self, summary: &Summary) -> Vec<(Part, Status)> {
+    return [(Part::One, &summary.part1), (Part::Two, &summary.part2)]
+      .into_iter()
+      .filter_map(|(part, result)| {
+        let status = match (self.get(part), &result.as_ref()?.0) {
+          (_, Err(e)) => Status::Error(e.clone()),
+          (None, _) => Status::Missing,
+          (Some(expected), Ok(actual)) if expected == actual => Status::Pass,
+          (Some(expected), Ok(actual)) => Status::Fail {
+            expected: expected.clone(),
+            actual: actual.clone(),
+          },
+        };
+        return Some((part, status));
+      })
+      .collect();
+  }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+  Pass,
+  Fail {
+    expected: String,
+    actual: String,
+  },
+  /// The part failed instead of giving an answer.
+  Error(Error),
+  /// There is no known answer yet, see `aoc record`.
+  Missing,
+}
@@ -0,0 +1,155 @@
+use std::{
+  env, fs,
+  path::{Path, PathBuf},
+  thread,
+  time::{Duration, SystemTime},
+};
+
+/// Where inputs are downloaded from unless `--endpoint` or `AOC_ENDPOINT` says
+/// otherwise, e.g. a local stand-in server.
+pub const ENDPOINT: &str = "https://adventofcode.com/2022";
+
+/// Downloads the puzzle inputs of an account into `DIR/dayN/input.txt`.
+pub struct Fetcher {
+  /// Inputs are at `{endpoint}/day/{N}/input`.
+  pub endpoint: String,
+  /// The value of the `session` cookie of a logged in browser.
+  pub session: String,
+  /// The least time between two requests, even across runs, see `wait`.
+  pub interval: Duration,
+  agent: ureq::Agent,
+}
+
+/// What `Fetcher::fetch` did with a day.
+pub enum Fetched {
+  /// The input was already there, nothing was requested.
+  Cached(PathBuf),
+  Downloaded(PathBuf),
+}
+
+impl Fetcher {
+  pub fn new(endpoint: &str, session: &str) -> Self {
+    return Fetcher {
+      endpoint: endpoint.trim_end_matches('/').to_string(),
+      session: session.to_string(),
+      interval: Duration::from_secs(5),
+      agent: ureq::AgentBuilder::new()
+        .timeout(Duration::from_secs(30))
+        .user_agent(concat!("aoc/", env!("CARGO_PKG_VERSION"), " (fetch)"))
+        .build(),
+    };
+  }
+
+  /// Where the input of `day` is when it's already in `data`. An empty file
+  /// counts as missing, like the one left by `aoc new`.
+  pub fn cached(data: &Path, day: u8) -> Option<PathBuf> {
+    let path = data.join(format!("day{day}")).join("input.txt");
+    return fs::metadata(&path)
+      .is_ok_and(|m| m.len() > 0)
+      .then_some(path);
+  }
+
+  /// Downloads the input of `day` unless it's already in `data`, see `cached`.
+  pub fn fetch(&self, data: &Path, day: u8) -> Result<Fetched, String> {
+    if let Some(path) = Fetcher::cached(data, day) {
+      return Ok(Fetched::Cached(path));
+    }
+    let path = data.join(format!("day{day}")).join("input.txt");
+
+    self.wait(data)?;
+    let url = format!("{}/day/{day}/input", self.endpoint);
+    let response = self
+      .agent
+      .get(&url)
+      .set("Cookie", &format!("session={}", self.session))
+      .call();
+    let input = match response {
+      Ok(response) => response.into_string().map_err(|e| format!("{url}: {e}"))?,
+      // The site answers 400 "please log in" for a missing or expired session.
+      Err(ureq::Error::Status(400 | 401 | 403, _)) => {
+        return Err(format!(
+          "{url}: the session token was rejected, it has probably expired; \
+           log in again and update {}",
+          session_sources()
+        ))
+      }
+      Err(ureq::Error::Status(404, _)) => {
+        return Err(format!("{url}: day {day} is not unlocked yet"))
+      }
+      Err(ureq::Error::Status(code, response)) => {
+        return Err(format!("{url}: {code} {}", response.status_text()))
+      }
+      Err(e) => return Err(format!("{url}: {e}")),
+    };
+    if input.is_empty() {
+      return Err(format!("{url}: the input is empty"));
+    }
+
+    // Written aside first, so an interrupted download is never mistaken for a
+    // cached input.
+    fs::create_dir_all(path.parent().unwrap()).map_err(|e| e.to_string())?;
+    let partial = path.with_extension("txt.part");
+    fs::write(&partial, input).map_err(|e| format!("{}: {e}", partial.display()))?;
+    fs::rename(&partial, &path).map_err(|e| format!("{}: {e}", path.display()))?;
+    return Ok(Fetched::Downloaded(path));
+  }
+
+  /// Sleeps until `interval` has passed since the last request, which is the
+  /// modification time of `DIR/.last-fetch`, then touches it.
+  fn wait(&self, data: &Path) -> Result<(), String> {
+    let stamp = data.join(".last-fetch");
+    let elapsed = fs::metadata(&stamp)
+      .and_then(|m| m.modified())
+      .ok()
+      .and_then(|last| SystemTime::now().duration_since(last).ok());
+    if let Some(elapsed) = elapsed {
+      thread::sleep(self.interval.saturating_sub(elapsed));
+    }
+
+    fs::create_dir_all(data).map_err(|e| e.to_string())?;
+    fs::write(&stamp, "").map_err(|e| format!("{}: {e}", stamp.display()))?;
+    return Ok(());
+  }
+}
+
+/// The session token from `AOC_SESSION`, or else from the config file, see
+/// `session_sources`. A `session=` prefix copied along with it is fine.
+pub fn session() -> Result<String, String> {
+  let token = match env::var("AOC_SESSION") {
+    Ok(token) => token,
+    Err(_) => match config_path().map(fs::read_to_string) {
+      Some(Ok(token)) => token,
+      _ => {
+        return Err(format!(
+          "no session token, copy the `session` cookie of adventofcode.com into {}",
+          session_sources()
+        ))
+      }
+    },
+  };
+
+  let token = token.trim();
+  let token = token.strip_prefix("session=").unwrap_or(token);
+  if token.is_empty() {
+    return Err(format!(
+      "the session token in {} is empty",
+      session_sources()
+    ));
+  }
+  return Ok(token.to_string());
+}
+
+/// `$XDG_CONFIG_HOME/aoc/session`, or `~/.config/aoc/session`.
+fn config_path() -> Option<PathBuf> {
+  let config = env::var_os("XDG_CONFIG_HOME")
+    .map(PathBuf::from)
+    .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+  return Some(config.join("aoc").join("session"));
+}
+
+fn session_sources() -> String {
+  return match config_path() {
+    Some(path) => format!("AOC_SESSION or {}", path.display()),
+    None => "AOC_SESSION".to_string(),
+  };
+}
@@ -0,0 +1,423 @@
+use std::{
+  fmt::Display,
+  ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// The integer types points are made of.
+pub trait Coordinate: Copy + Ord + Default + Add<Output = Self> + Sub<Output = Self> {
+  const ONE: Self;
+  /// Distances are unsigned, even between signed coordinates.
+  type Distance: Copy + Ord + Add<Output = Self::Distance>;
+
+  fn distance(self, other: Self) -> Self::Distance;
+}
+
+macro_rules! coordinate {
+  ($($t:ty => $distance:ty),*) => {
+    $(
+      impl Coordinate for $t {
+        const ONE: Self = 1;
+        type Distance = $distance;
+
+        fn distance(self, other: Self) -> $distance {
+          return self.abs_diff(other);
+        }
+      }
+    )*
+  };
+}
+
+coordinate!(
+  i8 => u8, i16 => u16, i32 => u32, i64 => u64, isize => usize,
+  u8 => u8, u16 => u16, u32 => u32, u64 => u64, usize => usize
+);
+
+/// The four ways to move on a 2D map. `y` grows downwards like the rows of a
+/// `Grid`, so `Up` decreases it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+  Up,
+  Right,
+  Down,
+  Left,
+}
+
+impl Direction {
+  /// Clockwise from `Up`.
+  pub const ALL: [Direction; 4] = [
+    Direction::Up,
+    Direction::Right,
+    Direction::Down,
+    Direction::Left,
+  ];
+
+  pub fn turn_right(self) -> Self {
+    return Self::ALL[(self as usize + 1) % 4];
+  }
+
+  pub fn turn_left(self) -> Self {
+    return Self::ALL[(self as usize + 3) % 4];
+  }
+
+  pub fn reverse(self) -> Self {
+    return Self::ALL[(self as usize + 2) % 4];
+  }
+}
+
+/// `Direction` and the diagonals in between.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+  Up,
+  UpRight,
+  Right,
+  DownRight,
+  Down,
+  DownLeft,
+  Left,
+  UpLeft,
+}
+
+impl Direction8 {
+  /// Clockwise from `Up`.
+  pub const ALL: [Direction8; 8] = [
+    Direction8::Up,
+    Direction8::UpRight,
+    Direction8::Right,
+    Direction8::DownRight,
+    Direction8::Down,
+    Direction8::DownLeft,
+    Direction8::Left,
+    Direction8::UpLeft,
+  ];
+}
+
+impl From<Direction> for Direction8 {
+  fn from(direction: Direction) -> Self {
+    return Direction8::ALL[direction as usize * 2];
+  }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+  pub x: T,
+  pub y: T,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+  pub x: T,
+  pub y: T,
+  pub z: T,
+}
+
+impl<T> Point2<T> {
+  pub const fn new(x: T, y: T) -> Self {
+    return Point2 { x, y };
+  }
+}
+
+impl<T> Point3<T> {
+  pub const fn new(x: T, y: T, z: T) -> Self {
+    return Point3 { x, y, z };
+  }
+}
+
+impl<T: Coordinate> Point2<T> {
+  /// The sum of the distances along each axis.
+  pub fn manhattan(&self, other: &Self) -> T::Distance {
+    return self.x.distance(other.x) + self.y.distance(other.y);
+  }
+
+  /// The largest of the distances along each axis, i.e. how many king moves
+  /// away `other` is.
+  pub fn chebyshev(&self, other: &Self) -> T::Distance {
+    return self.x.distance(other.x).max(self.y.distance(other.y));
+  }
+
+  /// The next point towards `direction`. Stepping an unsigned point below 0
+  /// panics in debug builds like any subtraction, use `Grid::offset` for
+  /// positions that may be on the edge.
+  pub fn step(&self, direction: impl Into<Direction8>) -> Self {
+    let Point2 { x, y } = *self;
+    let one = T::ONE;
+    let (x, y) = match direction.into() {
+      Direction8::Up => (x, y - one),
+      Direction8::UpRight => (x + one, y - one),
+      Direction8::Right => (x + one, y),
+      Direction8::DownRight => (x + one, y + one),
+      Direction8::Down => (x, y + one),
+      Direction8::DownLeft => (x - one, y + one),
+      Direction8::Left => (x - one, y),
+      Direction8::UpLeft => (x - one, y - one),
+    };
+    return Point2 { x, y };
+  }
+
+  /// Up, right, down and left.
+  pub fn neighbours4(&self) -> [Self; 4] {
+    return Direction::ALL.map(|direction| self.step(direction));
+  }
+
+  /// Every point around, clockwise from up.
+  pub fn neighbours8(&self) -> [Self; 8] {
+    return Direction8::ALL.map(|direction| self.step(direction));
+  }
+}
+
+impl<T: Coordinate> Point3<T> {
+  pub fn manhattan(&self, other: &Self) -> T::Distance {
+    return self.x.distance(other.x) + self.y.distance(other.y) + self.z.distance(other.z);
+  }
+
+  pub fn chebyshev(&self, other: &Self) -> T::Distance {
+    return self
+      .x
+      .distance(other.x)
+      .max(self.y.distance(other.y))
+      .max(self.z.distance(other.z));
+  }
+
+  /// The points sharing a face: -x, +x, -y, +y, -z and +z.
+  pub fn neighbours6(&self) -> [Self; 6] {
+    let Point3 { x, y, z } = *self;
+    let one = T::ONE;
+    return [
+      Point3::new(x - one, y, z),
+      Point3::new(x + one, y, z),
+      Point3::new(x, y - one, z),
+      Point3::new(x, y + one, z),
+      Point3::new(x, y, z - one),
+      Point3::new(x, y, z + one),
+    ];
+  }
+
+  /// The points sharing a face, an edge or a corner.
+  pub fn neighbours26(&self) -> [Self; 26] {
+    let shift = |v: T, i: usize| match i {
+      0 => v - T::ONE,
+      1 => v,
+      _ => v + T::ONE,
+    };
+    return std::array::from_fn(|i| {
+      // Skips 13, which is `self`.
+      let i = if i < 13 { i } else { i + 1 };
+      return Point3::new(
+        shift(self.x, i / 9),
+        shift(self.y, i / 3 % 3),
+        shift(self.z, i % 3),
+      );
+    });
+  }
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+  type Output = Self;
+
+  fn add(self, other: Self) -> Self {
+    return Point2::new(self.x + other.x, self.y + other.y);
+  }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+  type Output = Self;
+
+  fn sub(self, other: Self) -> Self {
+    return Point2::new(self.x - other.x, self.y - other.y);
+  }
+}
+
+impl<T: Neg<Output = T>> Neg for Point2<T> {
+  type Output = Self;
+
+  fn neg(self) -> Self {
+    return Point2::new(-self.x, -self.y);
+  }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point2<T> {
+  type Output = Self;
+
+  fn mul(self, scale: T) -> Self {
+    return Point2::new(self.x * scale, self.y * scale);
+  }
+}
+
+impl<T: Add<Output = T> + Copy> AddAssign for Point2<T> {
+  fn add_assign(&mut self, other: Self) {
+    *self = *self + other;
+  }
+}
+
+impl<T: Sub<Output = T> + Copy> SubAssign for Point2<T> {
+  fn sub_assign(&mut self, other: Self) {
+    *self = *self - other;
+  }
+}
+
+impl<T: Add<Output = T>> Add for Point3<T> {
+  type Output = Self;
+
+  fn add(self, other: Self) -> Self {
+    return Point3::new(self.x + other.x, self.y + other.y, self.z + other.z);
+  }
+}
+
+impl<T: Sub<Output = T>> Sub for Point3<T> {
+  type Output = Self;
+
+  fn sub(self, other: Self) -> Self {
+    return Point3::new(self.x - other.x, self.y - other.y, self.z - other.z);
+  }
+}
+
+impl<T: Neg<Output = T>> Neg for Point3<T> {
+  type Output = Self;
+
+  fn neg(self) -> Self {
+    return Point3::new(-self.x, -self.y, -self.z);
+  }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point3<T> {
+  type Output = Self;
+
+  fn mul(self, scale: T) -> Self {
+    return Point3::new(self.x * scale, self.y * scale, self.z * scale);
+  }
+}
+
+impl<T: Add<Output = T> + Copy> AddAssign for Point3<T> {
+  fn add_assign(&mut self, other: Self) {
+    *self = *self + other;
+  }
+}
+
+impl<T: Sub<Output = T> + Copy> SubAssign for Point3<T> {
+  fn sub_assign(&mut self, other: Self) {
+    *self = *self - other;
+  }
+}
+
+/// Like the puzzles write them, `x,y`.
+impl<T: Display> Display for Point2<T> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    return write!(f, "{},{}", self.x, self.y);
+  }
+}
+
+/// Like the puzzles write them, `x,y,z`.
+impl<T: Display> Display for Point3<T> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    return write!(f, "{},{},{}", self.x, self.y, self.z);
+  }
+}
+
+/// The smallest box holding some points, both corners included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bounds2<T> {
+  pub min: Point2<T>,
+  pub max: Point2<T>,
+}
+
+/// The smallest box holding some points, both corners included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bounds3<T> {
+  pub min: Point3<T>,
+  pub max: Point3<T>,
+}
+
+impl<T: Coordinate> Bounds2<T> {
+  /// `None` when there are no points.
+  pub fn from_points(points: impl IntoIterator<Item = Point2<T>>) -> Option<Self> {
+    let mut points = points.into_iter();
+    let first = points.next()?;
+    return Some(points.fold(
+      Bounds2 {
+        min: first,
+        max: first,
+      },
+      |bounds, p| {
+        return bounds.include(p);
+      },
+    ));
+  }
+
+  /// The box grown to hold `p` too.
+  pub fn include(&self, p: Point2<T>) -> Self {
+    return Bounds2 {
+      min: Point2::new(self.min.x.min(p.x), self.min.y.min(p.y)),
+      max: Point2::new(self.max.x.max(p.x), self.max.y.max(p.y)),
+    };
+  }
+
+  /// The box grown by `margin` on every side.
+  pub fn grow(&self, margin: T) -> Self {
+    let margin = Point2::new(margin, margin);
+    return Bounds2 {
+      min: self.min - margin,
+      max: self.max + margin,
+    };
+  }
+
+  pub fn contains(&self, p: &Point2<T>) -> bool {
+    return (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y);
+  }
+
+  /// How many points fit along each axis.
+  pub fn size(&self) -> Point2<T> {
+    return self.max - self.min + Point2::new(T::ONE, T::ONE);
+  }
+}
+
+impl<T: Coordinate> Bounds3<T> {
+  /// `None` when there are no points.
+  pub fn from_points(points: impl IntoIterator<Item = Point3<T>>) -> Option<Self> {
+    let mut points = points.into_iter();
+    let first = points.next()?;
+    return Some(points.fold(
+      Bounds3 {
+        min: first,
+        max: first,
+      },
+      |bounds, p| {
+        return bounds.include(p);
+      },
+    ));
+  }
+
+  /// The box grown to hold `p` too.
+  pub fn include(&self, p: Point3<T>) -> Self {
+    return Bounds3 {
+      min: Point3::new(
+        self.min.x.min(p.x),
+        self.min.y.min(p.y),
+        self.min.z.min(p.z),
+      ),
+      max: Point3::new(
+        self.max.x.max(p.x),
+        self.max.y.max(p.y),
+        self.max.z.max(p.z),
+      ),
+    };
+  }
+
+  /// The box grown by `margin` on every side.
+  pub fn grow(&self, margin: T) -> Self {
+    let margin = Point3::new(margin, margin, margin);
+    return Bounds3 {
+      min: self.min - margin,
+      max: self.max + margin,
+    };
+  }
+
+  pub fn contains(&self, p: &Point3<T>) -> bool {
+    return (self.min.x..=self.max.x).contains(&p.x)
+      && (self.min.y..=self.max.y).contains(&p.y)
+      && (self.min.z..=self.max.z).contains(&p.z);
+  }
+
+  /// How many points fit along each axis.
+  pub fn size(&self) -> Point3<T> {
+    return self.max - self.min + Point3::new(T::ONE, T::ONE, T::ONE);
+  }
+}
@@ -0,0 +1,338 @@
+use std::{
+  fmt::Display,
+  ops::{Index, IndexMut},
+};
+
+use crate::{Error, Line, Point2, Result};
+
+/// A dense 2D map stored row by row, indexed by `(x, y)` or a `Point2` with
+/// `x` going right and `y` going down from the top left corner.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+  width: usize,
+  height: usize,
+  cells: Vec<T>,
+}
+
+/// Up, right, down and left, as `(dx, dy)`.
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Clockwise from up.
+const ALL: [(isize, isize); 8] = [
+  (0, -1),
+  (1, -1),
+  (1, 0),
+  (1, 1),
+  (0, 1),
+  (-1, 1),
+  (-1, 0),
+  (-1, -1),
+];
+
+/// What a `Grid` is indexed with.
+pub trait Position: Copy + 'static {
+  fn from_xy(x: usize, y: usize) -> Self;
+  fn xy(self) -> (usize, usize);
+}
+
+impl Position for (usize, usize) {
+  fn from_xy(x: usize, y: usize) -> Self {
+    return (x, y);
+  }
+
+  fn xy(self) -> (usize, usize) {
+    return self;
+  }
+}
+
+impl Position for Point2<usize> {
+  fn from_xy(x: usize, y: usize) -> Self {
+    return Point2::new(x, y);
+  }
+
+  fn xy(self) -> (usize, usize) {
+    return (self.x, self.y);
+  }
+}
+
+impl<T> Grid<T> {
+  pub fn new(width: usize, height: usize, value: T) -> Self
+  where
+    T: Clone,
+  {
+    return Grid {
+      width,
+      height,
+      cells: vec![value; width * height],
+    };
+  }
+
+  /// The cells row by row. Panics when they don't make whole rows.
+  pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+    assert!(
+      width > 0 && cells.len().is_multiple_of(width),
+      "{} cells don't make rows of {width}",
+      cells.len()
+    );
+    return Grid {
+      width,
+      height: cells.len() / width,
+      cells,
+    };
+  }
+
+  /// Parses a character map, one row per line, with `cell` telling what each
+  /// character is. Errors point at the unexpected character, or at the line
+  /// that is longer or shorter than the first one.
+  pub fn parse(lines: &[Line], cell: impl Fn(char) -> Option<T>) -> Result<Self> {
+    let width = lines.first().map_or(0, |line| line.chars().count());
+    if width == 0 {
+      return Err(match lines.first() {
+        Some(line) => line.error_at(1, "expected a map"),
+        None => Error::new("expected a map"),
+      });
+    }
+
+    let mut cells = Vec::with_capacity(width * lines.len());
+    for line in lines {
+      let count = line.chars().count();
+      if count != width {
+        return Err(line.error_at(
+          count.min(width) + 1,
+          format!("expected {width} columns but got {count}"),
+        ));
+      }
+      for (i, c) in line.char_indices() {
+        match cell(c) {
+          Some(value) => cells.push(value),
+          None => return Err(line.error(&line[i..i + c.len_utf8()], format!("unexpected `{c}`"))),
+        }
+      }
+    }
+    return Ok(Grid::from_vec(width, cells));
+  }
+
+  pub fn width(&self) -> usize {
+    return self.width;
+  }
+
+  pub fn height(&self) -> usize {
+    return self.height;
+  }
+
+  pub fn contains(&self, position: impl Position) -> bool {
+    let (x, y) = position.xy();
+    return x < self.width && y < self.height;
+  }
+
+  pub fn get(&self, position: impl Position) -> Option<&T> {
+    let (x, y) = position.xy();
+    return self
+      .contains(position)
+      .then(|| &self.cells[y * self.width + x]);
+  }
+
+  pub fn get_mut(&mut self, position: impl Position) -> Option<&mut T> {
+    if !self.contains(position) {
+      return None;
+    }
+    let (x, y) = position.xy();
+    return Some(&mut self.cells[y * self.width + x]);
+  }
+
+  /// `position` moved by `(dx, dy)`, unless that falls outside of the grid.
+  pub fn offset<P: Position>(&self, position: P, (dx, dy): (isize, isize)) -> Option<P> {
+    let (x, y) = position.xy();
+    let position = P::from_xy(x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+    return self.contains(position).then_some(position);
+  }
+
+  /// Any position, wrapped around the edges as if the grid repeated forever.
+  pub fn wrap(&self, x: isize, y: isize) -> (usize, usize) {
+    return (
+      x.rem_euclid(self.width as isize) as usize,
+      y.rem_euclid(self.height as isize) as usize,
+    );
+  }
+
+  pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+    return &self[self.wrap(x, y)];
+  }
+
+  /// The positions up, right, down and left of `position` within the grid.
+  pub fn neighbours4<P: Position>(&self, position: P) -> impl Iterator<Item = P> + '_ {
+    return ORTHOGONAL
+      .into_iter()
+      .filter_map(move |delta| self.offset(position, delta));
+  }
+
+  /// `neighbours4` and the diagonals, clockwise from up.
+  pub fn neighbours8<P: Position>(&self, position: P) -> impl Iterator<Item = P> + '_ {
+    return ALL
+      .into_iter()
+      .filter_map(move |delta| self.offset(position, delta));
+  }
+
+  pub fn row(&self, y: usize) -> &[T] {
+    return &self.cells[y * self.width..(y + 1) * self.width];
+  }
+
+  pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+    return &mut self.cells[y * self.width..(y + 1) * self.width];
+  }
+
+  pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+    return self.cells.chunks(self.width.max(1));
+  }
+
+  /// Top to bottom, `rev` it to go up.
+  pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+    assert!(x < self.width, "column {x} out of {}", self.width);
+    return self.cells[x..].iter().step_by(self.width);
+  }
+
+  /// From `(x, y)` towards the bottom right corner.
+  pub fn diagonal(&self, (x, y): (usize, usize)) -> impl Iterator<Item = &T> {
+    let len = self
+      .width
+      .saturating_sub(x)
+      .min(self.height.saturating_sub(y));
+    return (0..len).map(move |i| &self[(x + i, y + i)]);
+  }
+
+  /// From `(x, y)` towards the bottom left corner.
+  pub fn anti_diagonal(&self, (x, y): (usize, usize)) -> impl Iterator<Item = &T> {
+    let len = if x < self.width {
+      (x + 1).min(self.height.saturating_sub(y))
+    } else {
+      0
+    };
+    return (0..len).map(move |i| &self[(x - i, y + i)]);
+  }
+
+  /// Row by row.
+  pub fn iter(&self) -> std::slice::Iter<'_, T> {
+    return self.cells.iter();
+  }
+
+  /// Every position, row by row.
+  pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+    let width = self.width;
+    return (0..self.cells.len()).map(move |i| (i % width, i / width));
+  }
+
+  /// Every cell with its position, row by row.
+  pub fn enumerate(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+    return self.positions().zip(self.cells.iter());
+  }
+
+  /// The first position, row by row, whose cell matches.
+  pub fn find(&self, matches: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+    let i = self.cells.iter().position(matches)?;
+    return Some((i % self.width, i / self.width));
+  }
+
+  /// Adds a row at the bottom, it must be `width` long.
+  pub fn push_row(&mut self, row: impl IntoIterator<Item = T>) {
+    let len = self.cells.len();
+    self.cells.extend(row);
+    assert_eq!(
+      self.cells.len() - len,
+      self.width,
+      "rows are {} long",
+      self.width
+    );
+    self.height += 1;
+  }
+
+  pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+    return Grid {
+      width: self.width,
+      height: self.height,
+      cells: self.cells.iter().map(f).collect(),
+    };
+  }
+
+  /// Rows become columns, `(x, y)` moves to `(y, x)`.
+  pub fn transpose(&self) -> Self
+  where
+    T: Clone,
+  {
+    return self.rebuild(self.height, self.width, |(x, y)| (y, x));
+  }
+
+  /// Clockwise, the left column becomes the top row.
+  pub fn rotate_right(&self) -> Self
+  where
+    T: Clone,
+  {
+    return self.rebuild(self.height, self.width, |(x, y)| (y, self.height - 1 - x));
+  }
+
+  /// Counterclockwise, the top row becomes the left column.
+  pub fn rotate_left(&self) -> Self
+  where
+    T: Clone,
+  {
+    return self.rebuild(self.height, self.width, |(x, y)| (self.width - 1 - y, x));
+  }
+
+  /// A `width` × `height` grid taking each cell from `source(x, y)` in this one.
+  fn rebuild(
+    &self,
+    width: usize,
+    height: usize,
+    source: impl Fn((usize, usize)) -> (usize, usize),
+  ) -> Self
+  where
+    T: Clone,
+  {
+    let cells = (0..width * height)
+      .map(|i| self[source((i % width, i / width))].clone())
+      .collect();
+    return Grid {
+      width,
+      height,
+      cells,
+    };
+  }
+}
+
+impl<T, P: Position> Index<P> for Grid<T> {
+  type Output = T;
+
+  fn index(&self, position: P) -> &T {
+    let (width, height) = (self.width, self.height);
+    return self.get(position).unwrap_or_else(|| {
+      panic!(
+        "{:?} is outside of the {width}x{height} grid",
+        position.xy()
+      )
+    });
+  }
+}
+
+impl<T, P: Position> IndexMut<P> for Grid<T> {
+  fn index_mut(&mut self, position: P) -> &mut T {
+    let (width, height) = (self.width, self.height);
+    return self.get_mut(position).unwrap_or_else(|| {
+      panic!(
+        "{:?} is outside of the {width}x{height} grid",
+        position.xy()
+      )
+    });
+  }
+}
+
+/// Every cell next to each other, one row per line.
+impl<T: Display> Display for Grid<T> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    for row in self.rows() {
+      for cell in row {
+        write!(f, "{cell}")?;
+      }
+      writeln!(f)?;
+    }
+    return Ok(());
+  }
+}
@@ -0,0 +1,260 @@
+use std::{
+  fmt::Display,
+  fs::File,
+  io::{self, BufRead, BufReader},
+  ops::Deref,
+  str::FromStr,
+};
+
+use crate::{Error, Result};
+
+/// A line of the puzzle input that knows where it came from, so that errors
+/// can point at the bad token. Derefs to the text of the line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Line {
+  /// 1-based.
+  pub number: usize,
+  pub text: String,
+}
+
+impl Deref for Line {
+  type Target = str;
+
+  fn deref(&self) -> &Self::Target {
+    return &self.text;
+  }
+}
+
+impl Line {
+  pub fn new(number: usize, text: impl Into<String>) -> Self {
+    return Line {
+      number,
+      text: text.into(),
+    };
+  }
+
+  /// Error pointing at the 1-based character `column`.
+  pub fn error_at(&self, column: usize, message: impl Display) -> Error {
+    return Error {
+      file: None,
+      line: self.number,
+      column,
+      text: Some(self.text.clone()),
+      message: message.to_string(),
+    };
+  }
+
+  /// Error pointing at `token`, which should be a slice of this line, e.g. from
+  /// `split`. Otherwise it points at the start of the line.
+  pub fn error(&self, token: &str, message: impl Display) -> Error {
+    let start = self.text.as_ptr() as usize;
+    let offset = (token.as_ptr() as usize).wrapping_sub(start);
+    let column = if offset <= self.text.len() && self.text.is_char_boundary(offset) {
+      self.text[..offset].chars().count() + 1
+    } else {
+      1
+    };
+    return self.error_at(column, message);
+  }
+
+  /// Parses `token`, a slice of this line, pointing at it when it fails.
+  pub fn parse<T>(&self, token: &str) -> Result<T>
+  where
+    T: FromStr,
+    T::Err: Display,
+  {
+    return token
+      .parse()
+      .map_err(|e| self.error(token, format!("invalid `{token}`: {e}")));
+  }
+
+  /// Unwraps the next token of an iterator over this line, pointing past the
+  /// end of the line when there are none left.
+  pub fn expect<'a>(&self, token: Option<&'a str>, what: &str) -> Result<&'a str> {
+    return token
+      .ok_or_else(|| self.error_at(self.text.chars().count() + 1, format!("expected {what}")));
+  }
+
+  /// Matches the line against a `template` with a `{}` for each of the `N`
+  /// fields, e.g. `"Sensor at x={}, y={}"`, and returns the text of the fields.
+  /// Whitespace in the template matches any amount of it. A field ends where
+  /// the text after it starts, or at the end of the line for the last one.
+  ///
+  /// Errors point at where the line stops following the template, `aoc::scan!`
+  /// also parses the fields. Panics when the template doesn't have `N` fields
+  /// or two fields aren't separated by text.
+  pub fn scan<const N: usize>(&self, template: &str) -> Result<[&str; N]> {
+    let literals = template.split("{}").collect::<Vec<_>>();
+    assert!(
+      literals.len() == N + 1,
+      "`{template}` has {} fields, not {N}",
+      literals.len() - 1
+    );
+
+    let mut fields = [""; N];
+    let mut pos = self.match_literal(literals[0], 0)?;
+    for (i, literal) in literals[1..].iter().enumerate() {
+      let end = if literal.is_empty() {
+        assert!(
+          i == N - 1,
+          "fields of `{template}` must be separated by text"
+        );
+        self.text.len()
+      } else {
+        let gap = literal.starts_with(char::is_whitespace);
+        let first = literal.trim_start().chars().next();
+        self.text[pos..]
+          .find(|c: char| Some(c) == first || (gap && c.is_whitespace()))
+          .map_or(self.text.len(), |offset| pos + offset)
+      };
+      fields[i] = &self.text[pos..end];
+      pos = self.match_literal(literal, end)?;
+    }
+
+    if pos < self.text.len() {
+      let rest = &self.text[pos..];
+      return Err(self.error(rest, format!("unexpected `{rest}`")));
+    }
+    return Ok(fields);
+  }
+
+  /// Where `literal` ends when it's found at `pos`.
+  fn match_literal(&self, literal: &str, mut pos: usize) -> Result<usize> {
+    let mut expected = literal.char_indices().peekable();
+    while let Some((i, c)) = expected.next() {
+      if c.is_whitespace() {
+        while expected.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+        let rest = &self.text[pos..];
+        pos += rest.len() - rest.trim_start().len();
+        continue;
+      }
+      if !self.text[pos..].starts_with(c) {
+        // From the start of the word, which reads better than half of it.
+        let word = literal[..i].rfind(char::is_whitespace).map_or(0, |w| w + 1);
+        let message = format!("expected `{}`", literal[word..].trim_end());
+        return Err(self.error(&self.text[pos..], message));
+      }
+      pos += c.len_utf8();
+    }
+    return Ok(pos);
+  }
+}
+
+/// Matches a `Line` against a template like `Line::scan` and parses each field
+/// as the given types, pointing at the one that fails. For instance
+/// `aoc::scan!(line, "move {} from {} to {}", usize, u8, u8)` gives an
+/// `aoc::Result<(usize, u8, u8)>`.
+#[macro_export]
+macro_rules! scan {
+  ($line:expr, $template:expr, $($type:ty),+ $(,)?) => {{
+    let line: &$crate::Line = &$line;
+    const N: usize = [$(stringify!($type)),+].len();
+    line.scan::<N>($template).and_then(|fields| {
+      let mut fields = fields.into_iter();
+      return Ok(($(line.parse::<$type>(fields.next().unwrap())?,)+));
+    })
+  }};
+}
+
+/// Reads all the lines from `path`, or from stdin when `path` is `-`. Trailing
+/// blank lines are dropped, but there is always at least one line.
+pub fn lines(path: &str) -> Result<Vec<Line>> {
+  return Lines::open(path)?.all();
+}
+
+/// Streams the lines of any `BufRead` without loading all of it in memory.
+///
+/// Lines end in `\n` or `\r\n`, neither is part of the `Line`.
+pub struct Lines {
+  reader: Box<dyn BufRead>,
+  number: usize,
+}
+
+impl Lines {
+  pub fn new(reader: impl BufRead + 'static) -> Self {
+    return Lines {
+      reader: Box::new(reader),
+      number: 0,
+    };
+  }
+
+  /// Opens `path`, or stdin when `path` is `-`.
+  pub fn open(path: &str) -> Result<Self> {
+    if path == "-" {
+      return Ok(Lines::new(io::stdin().lock()));
+    }
+    let file = File::open(path).map_err(Error::new)?;
+    return Ok(Lines::new(BufReader::new(file)));
+  }
+
+  /// The remaining lines, the same way `aoc::lines` reads them.
+  pub fn all(self) -> Result<Vec<Line>> {
+    let mut lines = self.collect::<Result<Vec<Line>>>()?;
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+      lines.pop();
+    }
+    if lines.is_empty() {
+      lines.push(Line::new(1, ""));
+    }
+    return Ok(lines);
+  }
+
+  /// The remaining lines in groups separated by blank lines.
+  pub fn groups(self) -> Groups {
+    return Groups { lines: self };
+  }
+}
+
+impl Iterator for Lines {
+  type Item = Result<Line>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let mut text = String::new();
+    self.number += 1;
+    match self.reader.read_line(&mut text) {
+      Ok(0) => return None,
+      Ok(_) => (),
+      Err(e) => {
+        return Some(Err(Error {
+          line: self.number,
+          ..Error::new(e)
+        }))
+      }
+    }
+
+    if text.ends_with('\n') {
+      text.pop();
+      if text.ends_with('\r') {
+        text.pop();
+      }
+    }
+    return Some(Ok(Line::new(self.number, text)));
+  }
+}
+
+/// Groups of lines separated by one or more blank lines, see `Lines::groups`.
+/// None of the groups is empty.
+pub struct Groups {
+  lines: Lines,
+}
+
+impl Iterator for Groups {
+  type Item = Result<Vec<Line>>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let mut group = vec![];
+    for line in self.lines.by_ref() {
+      match line {
+        Err(e) => return Some(Err(e)),
+        Ok(line) if line.trim().is_empty() && group.is_empty() => continue,
+        Ok(line) if line.trim().is_empty() => break,
+        Ok(line) => group.push(line),
+      }
+    }
+
+    if group.is_empty() {
+      return None;
+    }
+    return Some(Ok(group));
+  }
+}
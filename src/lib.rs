@@ -1,66 +1,210 @@
-use std::{
-  fmt::{Debug, Display},
-  fs,
-  time::Instant,
-};
-
-fn measure_time<T, X>(f: &dyn Fn(X) -> T, arg: X) -> (T, Duration) {
-  let start = Instant::now();
-  return (f(arg), Duration(start.elapsed()));
+use std::{fmt::Display, time::Instant};
+
+mod alloc;
+mod baseline;
+mod bench;
+mod charmask;
+pub mod days;
+mod error;
+mod expected;
+mod fetch;
+mod geometry;
+mod grid;
+mod input;
+mod options;
+mod output;
+mod parallel;
+mod scaffold;
+mod search;
+#[cfg(target_os = "linux")]
+mod watch;
+
+pub use alloc::{Bytes, Memory};
+pub use baseline::{Baseline, Comparison};
+pub use bench::{Benchmark, Stats};
+pub use charmask::{Alphabet, Ascii, CharMask, Digits, Letters};
+pub use error::{Error, Result};
+pub use expected::{Expected, Status};
+pub use fetch::{session, Fetched, Fetcher, ENDPOINT};
+pub use geometry::{Bounds2, Bounds3, Coordinate, Direction, Direction8, Point2, Point3};
+pub use grid::{Grid, Position};
+pub use input::{lines, Groups, Line, Lines};
+pub use options::{Options, Part, Source, USAGE};
+pub use output::{from_csv, to_csv, to_json, Format, Record};
+pub use parallel::run_ordered;
+pub use scaffold::scaffold;
+pub use search::{astar, bfs, bfs_distances, dijkstra, Route};
+#[cfg(target_os = "linux")]
+pub use watch::{Filter, Watcher};
+
+/// The memory is only measured with the `alloc-stats` feature.
+fn measure_time<T, X>(f: &dyn Fn(X) -> T, arg: X) -> (T, Duration, Option<Memory>) {
+  let ((result, duration), memory) = alloc::measure(|| {
+    let start = Instant::now();
+    return (f(arg), Duration(start.elapsed()));
+  });
+  return (result, duration, memory);
 }
 
-fn measure_and_print<T, X>(name: &str, f: &dyn Fn(X) -> T, arg: X)
+fn measure_part<T, X>(f: &dyn Fn(X) -> Result<T>, arg: X) -> (Answer, Option<Memory>)
 where
-  T: Debug,
+  T: Display,
 {
-  let (result, duration) = measure_time(f, arg);
-  println!("{name} ({duration}): {:#?}", result);
+  let (result, duration, memory) = measure_time(f, arg);
+  return ((result.map(|v| v.to_string()), duration), memory);
 }
 
-pub fn lines(path: String) -> Vec<String> {
-  return fs::read_to_string(path)
-    .unwrap()
-    .trim_end()
-    .split("\n")
-    .map(|line| line.to_string())
-    .collect();
+/// A day's puzzle: how to parse its input and how to solve both parts.
+///
+/// Register it in `days::DAYS` through `aoc::solve`.
+pub trait Solver {
+  type Input;
+  type Output1: Display;
+  type Output2: Display;
+
+  /// Errors should point at the bad token, see `Line::error`. Either stream
+  /// the `Lines` or read them `all` at once.
+  fn parse(&self, input: Lines) -> Result<Self::Input>;
+  /// Parts that need to mutate the input clone it themselves, so it shows up
+  /// in their timings.
+  fn part1(&self, input: &Self::Input) -> Result<Self::Output1>;
+  fn part2(&self, input: &Self::Input) -> Result<Self::Output2>;
 }
 
-fn internal_solve<D, T1, T2>(
-  size: &str,
-  reader: &dyn Fn(String) -> D,
-  p1: &dyn Fn(D) -> T1,
-  p2: &dyn Fn(D) -> T2,
-) where
-  T1: Debug,
-  T2: Debug,
-  D: Clone,
-{
-  let current_exe = std::env::current_exe()
-    .unwrap()
-    .file_name()
-    .unwrap()
-    .to_os_string()
-    .into_string()
-    .unwrap();
-
-  let (data, duration) = measure_time(reader, format!("./data/{current_exe}/{size}.txt"));
-  println!("\nread ({duration}) --- {size}");
-  measure_and_print("part 1", &p1, data.clone());
-  measure_and_print("part 2", &p2, data);
+/// The answer of a part, or why it failed, and how long it took.
+pub type Answer = (Result<String>, Duration);
+
+/// Answers and timings of a day for a single source.
+pub struct Summary {
+  pub source: Source,
+  pub read: Duration,
+  /// Set when the input couldn't be read or parsed, no part runs then.
+  pub error: Option<Error>,
+  /// `None` when the part was not picked in `Options`, or the input had errors.
+  pub part1: Option<Answer>,
+  pub part2: Option<Answer>,
+  /// Named `read`, `part 1` and `part 2` like `Benchmark::phases`, empty
+  /// without the `alloc-stats` feature.
+  pub memory: Vec<(&'static str, Memory)>,
 }
 
-pub fn solve<D, T1, T2>(reader: &dyn Fn(String) -> D, p1: &dyn Fn(D) -> T1, p2: &dyn Fn(D) -> T2)
-where
-  T1: Debug,
-  T2: Debug,
-  D: Clone,
-{
-  internal_solve("small", reader, p1, p2);
-  internal_solve("input", reader, p1, p2);
+impl Summary {
+  /// The answers as they are printed for `Format::Text`, errors go to stderr.
+  pub fn print(&self, day: &str) {
+    println!("\nread ({}) --- {day} {}", self.read, self.source.name());
+    if let Some(e) = &self.error {
+      eprintln!("error: {e}");
+    }
+    for (name, part) in [("part 1", &self.part1), ("part 2", &self.part2)] {
+      match part {
+        // Multi-line answers (like day10's screen) read better starting on their own line.
+        Some((Ok(answer), duration)) if answer.contains('\n') => {
+          println!("{name} ({duration}):\n{answer}")
+        }
+        Some((Ok(answer), duration)) => println!("{name} ({duration}): {answer}"),
+        Some((Err(e), duration)) => println!("{name} ({duration}): error: {e}"),
+        None => (),
+      }
+    }
+  }
+}
+
+/// Reads and parses `path`, errors point at the file.
+fn read<S: Solver>(solver: &S, path: &str) -> Result<S::Input> {
+  let file = if path == "-" { "stdin" } else { path };
+  let input = Lines::open(path).map_err(|e| e.in_file(file))?;
+  return solver.parse(input).map_err(|e| e.in_file(file));
+}
+
+fn internal_solve<S: Solver>(options: &Options, day: &str, source: &Source, solver: &S) -> Summary {
+  let (data, read, memory) = measure_time(
+    &|path: String| read(solver, &path),
+    source.path(&options.data, day),
+  );
+  let mut summary = Summary {
+    source: source.clone(),
+    read,
+    error: None,
+    part1: None,
+    part2: None,
+    memory: memory.map(|m| vec![("read", m)]).unwrap_or_default(),
+  };
+  let data = match data {
+    Ok(data) => data,
+    Err(e) => {
+      summary.error = Some(e);
+      return summary;
+    }
+  };
+
+  if options.runs(Part::One) {
+    let (answer, memory) = measure_part(&|d| solver.part1(d), &data);
+    summary.part1 = Some(answer);
+    summary.memory.extend(memory.map(|m| ("part 1", m)));
+  }
+  if options.runs(Part::Two) {
+    let (answer, memory) = measure_part(&|d| solver.part2(d), &data);
+    summary.part2 = Some(answer);
+    summary.memory.extend(memory.map(|m| ("part 2", m)));
+  }
+  return summary;
 }
 
-struct Duration(std::time::Duration);
+/// What the runner needs from a day, see `Solver`.
+pub trait Solution {
+  /// Reads `source` and runs the parts picked in `options`.
+  fn run(&self, day: &str, source: &Source, options: &Options) -> Summary;
+
+  /// Times the reader and each part separately over many iterations.
+  fn bench(&self, day: &str, options: &Options) -> Vec<Result<Benchmark>>;
+}
+
+impl<S: Solver> Solution for S {
+  fn run(&self, day: &str, source: &Source, options: &Options) -> Summary {
+    return internal_solve(options, day, source, self);
+  }
+
+  fn bench(&self, day: &str, options: &Options) -> Vec<Result<Benchmark>> {
+    return options
+      .sources
+      .iter()
+      .map(|source| {
+        let path = source.path(&options.data, day);
+        let (data, read) = bench::sample(options, &|| read(self, &path));
+        let data = data?;
+
+        let part1 = if options.runs(Part::One) {
+          let (answer, stats) = bench::sample(options, &|| self.part1(&data));
+          answer?;
+          Some(stats)
+        } else {
+          None
+        };
+        let part2 = if options.runs(Part::Two) {
+          let (answer, stats) = bench::sample(options, &|| self.part2(&data));
+          answer?;
+          Some(stats)
+        } else {
+          None
+        };
+        return Ok(Benchmark {
+          source: source.clone(),
+          read,
+          part1,
+          part2,
+        });
+      })
+      .collect();
+  }
+}
+
+/// Bundles a day's solver so the runner can pick it up.
+pub fn solve<S: Solver + 'static>(solver: S) -> Box<dyn Solution> {
+  return Box::new(solver);
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Duration(pub std::time::Duration);
 
 impl Display for Duration {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -86,71 +230,9 @@ impl Display for Duration {
     .collect::<Vec<String>>()
     .join(" ");
 
-    return write!(f, "{output}");
-  }
-}
-
-#[derive(Copy, Clone)]
-pub struct CharMask([bool; 53]);
-
-impl FromIterator<u8> for CharMask {
-  fn from_iter<T: IntoIterator<Item = u8>>(iter: T) -> Self {
-    let mut mask = CharMask([false; 53]);
-    for v in iter {
-      mask.0[v as usize] = true;
-    }
-    return mask;
-  }
-}
-
-impl FromIterator<char> for CharMask {
-  fn from_iter<T: IntoIterator<Item = char>>(iter: T) -> Self {
-    let mut mask = CharMask([false; 53]);
-    for v in iter {
-      match v {
-        'a'..='z' => mask.0[(v as u8 - b'a' + 1) as usize] = true,
-        'A'..='Z' => mask.0[(v as u8 - b'A' + 26 + 1) as usize] = true,
-        _ => panic!("not possible"),
-      }
-    }
-    return mask;
-  }
-}
-
-impl FromIterator<usize> for CharMask {
-  fn from_iter<T: IntoIterator<Item = usize>>(iter: T) -> Self {
-    let mut mask = CharMask([false; 53]);
-    for v in iter {
-      mask.0[v] = true;
+    if output.is_empty() {
+      return write!(f, "0ns");
     }
-    return mask;
-  }
-}
-
-impl IntoIterator for CharMask {
-  type Item = usize;
-
-  type IntoIter = std::iter::FilterMap<
-    std::iter::Enumerate<std::array::IntoIter<bool, 53>>,
-    &'static dyn Fn((usize, bool)) -> Option<Self::Item>,
-  >;
-
-  fn into_iter(self) -> Self::IntoIter {
-    return self.0.into_iter().enumerate().filter_map(&|(a, b)| {
-      if b {
-        Some(a)
-      } else {
-        None
-      }
-    });
-  }
-}
-
-impl CharMask {
-  pub fn intersect(&mut self, other: &CharMask) -> &mut CharMask {
-    for (i, v) in other.0.iter().enumerate() {
-      self.0[i] &= v;
-    }
-    return self;
+    return write!(f, "{output}");
   }
 }
@@ -0,0 +1,691 @@
+use std::{
+  env,
+  path::{Path, PathBuf},
+  process::{self, Command, Stdio},
+  time::Instant,
+};
+
+use aoc::{
+  days::{self, Day},
+  Answer, Baseline, Benchmark, Bytes, Comparison, Expected, Format, Options, Record, Source,
+  Status, Summary,
+};
+
+const USAGE: &str = "\
+usage: aoc run DAYS [options]
+       aoc check DAYS [options]
+       aoc record DAYS [options]
+       aoc bench DAYS [options]
+       aoc new DAY [--data DIR]
+       aoc fetch DAYS [--data DIR] [--endpoint URL]
+       aoc watch DAY [options]
+
+  run            print the answers and timings
+  check          compare the answers with the `.expected` files next to the inputs
+  record         save the answers as the new `.expected` files
+  bench          time the reader and each part over many iterations, comparing
+                 the medians with the baseline when there is one
+  new            create and register src/days/dayN.rs with empty datasets
+  fetch          download the missing DIR/dayN/input.txt with the session token
+                 in $AOC_SESSION or ~/.config/aoc/session
+  watch          rebuild and re-run a day whenever src/days/dayN.rs, the other
+                 sources in src/ or the datasets in DIR/dayN/ change, showing
+                 how the answers and timings changed since the last run
+  DAYS           a day, a list or a range: 7, 1,3,5, 1..=19, 1..19 or all";
+
+fn main() {
+  let mut args = std::env::args().skip(1);
+  let result = match args.next().as_deref() {
+    Some("run") => run(args),
+    Some("check") => check(args),
+    Some("record") => record(args),
+    Some("bench") => bench(args),
+    Some("new") => new(args),
+    Some("fetch") => fetch(args),
+    Some("watch") => watch(args),
+    _ => Err(format!("{USAGE}\n\n{}", aoc::USAGE)),
+  };
+
+  match result {
+    Ok(true) => (),
+    Ok(false) => process::exit(1),
+    Err(message) => {
+      eprintln!("{message}");
+      process::exit(2);
+    }
+  }
+}
+
+fn parse_args(
+  mut args: impl Iterator<Item = String>,
+) -> Result<(Vec<&'static Day>, Options), String> {
+  let days = parse_days(&args.next().ok_or("missing DAYS")?)?;
+  return Ok((days, Options::parse(args)?));
+}
+
+/// The reports of the other subcommands would get mixed up with the records.
+fn text_only(options: &Options) -> Result<(), String> {
+  if options.format != Format::Text {
+    return Err("`--format` is only supported by `aoc run`".to_string());
+  }
+  return Ok(());
+}
+
+/// What `solve` ran, and the datasets it skipped.
+struct Solved {
+  summaries: Vec<(String, Summary)>,
+  /// Inputs that weren't fetched yet, see `skip`.
+  missing: Vec<PathBuf>,
+}
+
+/// Runs every day and dataset in parallel, see `Options::jobs`, and prints
+/// the answers in order followed by the summary table, or the records for
+/// `--format`.
+fn solve(days: &[&'static Day], options: &Options) -> Result<Solved, String> {
+  let mut missing = vec![];
+  let tasks = days
+    .iter()
+    .flat_map(|day| options.sources.iter().map(move |source| (*day, source)))
+    .filter(|&(day, source)| match skip(day, source, options) {
+      Some(path) => {
+        missing.push(path);
+        false
+      }
+      None => true,
+    })
+    .collect::<Vec<_>>();
+
+  let start = Instant::now();
+  let mut summaries = vec![];
+  aoc::run_ordered(
+    options.jobs,
+    &tasks,
+    |(day, source)| (day.solution)().run(&day.name(), source, options),
+    |(day, source), summary| {
+      // Reported like an input with errors, so the other days still are.
+      let summary = summary.unwrap_or_else(|message| Summary {
+        source: (*source).clone(),
+        read: aoc::Duration(std::time::Duration::ZERO),
+        error: Some(aoc::Error::new(format!("panicked: {message}"))),
+        part1: None,
+        part2: None,
+        memory: vec![],
+      });
+      if options.format == Format::Text {
+        summary.print(&day.name());
+      }
+      summaries.push((day.name(), summary));
+    },
+  )?;
+
+  let records = || {
+    summaries
+      .iter()
+      .flat_map(|(day, summary)| Record::from_summary(day, summary))
+      .collect::<Vec<_>>()
+  };
+  match options.format {
+    Format::Text => {
+      println!();
+      print_table(&summaries);
+      if summaries
+        .iter()
+        .any(|(_, summary)| !summary.memory.is_empty())
+      {
+        println!();
+        print_memory(&summaries);
+      }
+      println!(
+        "\n{} runs in {} with --jobs {}",
+        tasks.len(),
+        aoc::Duration(start.elapsed()),
+        options.jobs
+      );
+      if options.jobs > 1 {
+        println!("the timings overlap, --jobs 1 runs them one at a time");
+      }
+    }
+    Format::Json => print!("{}", aoc::to_json(&records())),
+    Format::Csv => print!("{}", aoc::to_csv(&records())),
+  }
+  // Not on stdout, where they would get mixed up with the records.
+  for path in &missing {
+    eprintln!("missing {}", path.display());
+  }
+  return Ok(Solved { summaries, missing });
+}
+
+/// The path of the default `input` of `day` when it wasn't fetched yet and
+/// nothing is expected from it, which isn't worth failing for. Datasets named
+/// on the command line, or with known answers, fail when they are missing.
+fn skip(day: &Day, source: &Source, options: &Options) -> Option<PathBuf> {
+  if options.picked_sources || *source != Source::Dataset("input".to_string()) {
+    return None;
+  }
+  let path = PathBuf::from(source.path(&options.data, &day.name()));
+  let expected = source.expected_path(&options.data, &day.name())?;
+  if path.exists() || expected.exists() {
+    return None;
+  }
+  return Some(path);
+}
+
+fn run(args: impl Iterator<Item = String>) -> Result<bool, String> {
+  let (days, options) = parse_args(args)?;
+  let summaries = solve(&days, &options)?.summaries;
+  // Exit with an error when any input was malformed or any part failed.
+  let failed = |answer: &Option<aoc::Answer>| matches!(answer, Some((Err(_), _)));
+  return Ok(
+    summaries
+      .iter()
+      .all(|(_, s)| s.error.is_none() && !failed(&s.part1) && !failed(&s.part2)),
+  );
+}
+
+fn check(args: impl Iterator<Item = String>) -> Result<bool, String> {
+  let (days, options) = parse_args(args)?;
+  text_only(&options)?;
+  let Solved { summaries, missing } = solve(&days, &options)?;
+
+  println!();
+  let (mut passed, mut failed, mut missing) = (0, 0, missing.len());
+  for (day, summary) in &summaries {
+    if let Some(e) = &summary.error {
+      failed += 1;
+      println!("ERROR   {day} {}\n  {e}", summary.source.name());
+      continue;
+    }
+    let expected = match summary.source.expected_path(&options.data, day) {
+      Some(path) => Expected::read(&path).map_err(|e| e.to_string())?,
+      None => Expected::default(),
+    };
+
+    for (part, status) in expected.check(summary) {
+      let name = format!("{day} {} {part}", summary.source.name());
+      match status {
+        Status::Pass => passed += 1,
+        Status::Missing => {
+          missing += 1;
+          println!("missing {name}");
+        }
+        Status::Fail { expected, actual } => {
+          failed += 1;
+          println!("FAIL    {name}\n  expected: {expected}\n  actual:   {actual}");
+        }
+        Status::Error(e) => {
+          failed += 1;
+          println!("ERROR   {name}\n  {e}");
+        }
+      }
+    }
+  }
+
+  println!("{passed} passed, {failed} failed, {missing} missing");
+  return Ok(failed == 0);
+}
+
+fn record(args: impl Iterator<Item = String>) -> Result<bool, String> {
+  let (days, options) = parse_args(args)?;
+  text_only(&options)?;
+  let summaries = solve(&days, &options)?.summaries;
+
+  println!();
+  for (day, summary) in &summaries {
+    if summary.error.is_some() {
+      println!(
+        "skipped {day} {}: the input has errors",
+        summary.source.name()
+      );
+      continue;
+    }
+    let Some(path) = summary.source.expected_path(&options.data, day) else {
+      println!(
+        "skipped {day} {}: nowhere to record it",
+        summary.source.name()
+      );
+      continue;
+    };
+    let mut expected = Expected::read(&path).map_err(|e| e.to_string())?;
+    expected.update(summary);
+    expected.write(&path).map_err(|e| e.to_string())?;
+    println!("recorded {}", path.display());
+  }
+
+  return Ok(true);
+}
+
+fn bench(args: impl Iterator<Item = String>) -> Result<bool, String> {
+  let (days, options) = parse_args(args)?;
+  text_only(&options)?;
+  if options.sources.contains(&Source::Stdin) {
+    return Err("can't benchmark stdin, it can only be read once".to_string());
+  }
+
+  let benchmarks = days
+    .iter()
+    .flat_map(|day| {
+      eprintln!("benchmarking {}", day.name());
+      (day.solution)()
+        .bench(&day.name(), &options)
+        .into_iter()
+        .filter_map(|benchmark| match benchmark {
+          Ok(benchmark) => Some((day.name(), benchmark)),
+          Err(e) => {
+            eprintln!("error: {e}");
+            None
+          }
+        })
+        .collect::<Vec<_>>()
+    })
+    .collect::<Vec<_>>();
+
+  println!(
+    "{} warmup and {} timed iterations\n",
+    options.warmup, options.iterations
+  );
+  print_benchmarks(&benchmarks);
+
+  let path = options.baseline_path();
+  let mut baseline = Baseline::read(&path).map_err(|e| e.to_string())?;
+  let comparisons = benchmarks
+    .iter()
+    .flat_map(|(day, benchmark)| {
+      baseline
+        .compare(day, benchmark, options.threshold)
+        .into_iter()
+        .map(|comparison| (day.clone(), benchmark.source.name(), comparison))
+    })
+    .collect::<Vec<_>>();
+
+  if !comparisons.is_empty() {
+    println!("\ncompared with {}\n", path.display());
+    print_comparisons(&comparisons);
+  } else if !path.exists() && !options.save_baseline {
+    println!(
+      "\nno baseline in {} to compare with, save one with --save-baseline",
+      path.display()
+    );
+  }
+  let regressions = comparisons.iter().filter(|(.., c)| c.regressed).count();
+  if regressions > 0 {
+    println!(
+      "\n{regressions} got slower by more than {}%",
+      options.threshold
+    );
+  }
+
+  if options.save_baseline {
+    for (day, benchmark) in &benchmarks {
+      baseline.update(day, benchmark);
+    }
+    baseline.write(&path).map_err(|e| e.to_string())?;
+    println!("\nsaved baseline to {}", path.display());
+  }
+
+  return Ok(regressions == 0);
+}
+
+fn new(mut args: impl Iterator<Item = String>) -> Result<bool, String> {
+  let day = args.next().ok_or("missing DAY")?;
+  let day = day
+    .parse::<u8>()
+    .map_err(|_| format!("invalid day `{day}`"))?;
+  let options = Options::parse(args)?;
+  if days::find(day).is_some() {
+    return Err(format!("day {day} is already registered"));
+  }
+
+  let written = aoc::scaffold(Path::new("."), &options.data, day).map_err(|e| e.to_string())?;
+  for path in written {
+    println!("wrote {}", path.display());
+  }
+  println!("\nonce data/day{day}/small.txt has the example: aoc record {day} small");
+  return Ok(true);
+}
+
+fn fetch(mut args: impl Iterator<Item = String>) -> Result<bool, String> {
+  let spec = args.next().ok_or("missing DAYS")?;
+  // Unlike the other subcommands, days don't need to be registered yet.
+  let numbers = match spec.as_str() {
+    "all" => days::DAYS.iter().map(|day| day.number).collect(),
+    spec => parse_numbers(spec)?,
+  };
+  let options = Options::parse(args)?;
+
+  let endpoint = match &options.endpoint {
+    Some(endpoint) => endpoint.clone(),
+    None => env::var("AOC_ENDPOINT").unwrap_or_else(|_| aoc::ENDPOINT.to_string()),
+  };
+  // The session token is only needed once something has to be downloaded.
+  let mut fetcher = None;
+  for day in numbers {
+    if let Some(path) = aoc::Fetcher::cached(&options.data, day) {
+      println!("cached     {}", path.display());
+      continue;
+    }
+    let fetcher = match &mut fetcher {
+      Some(fetcher) => fetcher,
+      None => fetcher.insert(aoc::Fetcher::new(&endpoint, &aoc::session()?)),
+    };
+    match fetcher.fetch(&options.data, day)? {
+      aoc::Fetched::Cached(path) => println!("cached     {}", path.display()),
+      aoc::Fetched::Downloaded(path) => println!("downloaded {}", path.display()),
+    }
+  }
+  return Ok(true);
+}
+
+#[cfg(target_os = "linux")]
+fn watch(mut args: impl Iterator<Item = String>) -> Result<bool, String> {
+  let day = args.next().ok_or("missing DAY")?;
+  let day = day
+    .parse::<u8>()
+    .map_err(|_| format!("invalid day `{day}`"))?;
+  // Handed over to `aoc run`, the day doesn't need to be registered yet.
+  let rest = args.collect::<Vec<_>>();
+  let options = Options::parse(rest.clone())?;
+  text_only(&options)?;
+  // Taken before rebuilding, afterwards it may point at the replaced binary.
+  let exe = env::current_exe().map_err(|e| e.to_string())?;
+
+  let mut watcher = aoc::Watcher::new().map_err(|e| e.to_string())?;
+  let module = PathBuf::from(format!("day{day}.rs"));
+  let data = options.data.join(format!("day{day}"));
+  let watches: [(&Path, aoc::Filter); 3] = [
+    (Path::new("src/days"), Box::new(move |name| name == module)),
+    (
+      Path::new("src"),
+      Box::new(|name| name.extension() == Some("rs".as_ref())),
+    ),
+    (
+      &data,
+      Box::new(|name| name.extension() == Some("txt".as_ref())),
+    ),
+  ];
+  for (dir, filter) in watches {
+    watcher.add(dir, filter).map_err(|e| e.to_string())?;
+  }
+
+  let mut previous = None;
+  loop {
+    if let Some(records) = rerun(&exe, day, &rest)? {
+      print_changes(previous.as_deref(), &records);
+      previous = Some(records);
+    }
+
+    println!(
+      "\nwatching src/days/day{day}.rs, src/*.rs and {}/*.txt",
+      data.display()
+    );
+    let changed = watcher
+      .wait(std::time::Duration::from_millis(200))
+      .map_err(|e| e.to_string())?;
+    for path in changed {
+      println!("changed {}", path.display());
+    }
+  }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn watch(_args: impl Iterator<Item = String>) -> Result<bool, String> {
+  return Err("`aoc watch` needs inotify, it only works on Linux".to_string());
+}
+
+/// Rebuilds with the profile and features of this binary, then runs `day`
+/// with `args` and reads back its records. `None` when the build failed.
+fn rerun(exe: &Path, day: u8, args: &[String]) -> Result<Option<Vec<Record>>, String> {
+  println!();
+  let mut cargo = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+  cargo.args(["build", "--quiet", "--bin", "aoc"]);
+  if !cfg!(debug_assertions) {
+    cargo.arg("--release");
+  }
+  if cfg!(feature = "alloc-stats") {
+    cargo.args(["--features", "alloc-stats"]);
+  }
+  if !cargo.status().map_err(|e| format!("cargo: {e}"))?.success() {
+    println!("build failed");
+    return Ok(None);
+  }
+
+  let output = Command::new(exe)
+    .args(["run", &day.to_string()])
+    .args(args)
+    .args(["--format", "csv"])
+    .stderr(Stdio::inherit())
+    .output()
+    .map_err(|e| format!("{}: {e}", exe.display()))?;
+  // 1 only means that some part failed, which the records show.
+  if !matches!(output.status.code(), Some(0 | 1)) {
+    println!("aoc run {day} failed: {}", output.status);
+    return Ok(None);
+  }
+  return aoc::from_csv(&String::from_utf8_lossy(&output.stdout)).map(Some);
+}
+
+/// Prints the answers and timings of `aoc watch` next to the previous run.
+fn print_changes(previous: Option<&[Record]>, records: &[Record]) {
+  let answer = |r: &Record| match (&r.answer, &r.error) {
+    (_, Some(_)) => "error".to_string(),
+    (Some(answer), None) if answer.contains('\n') => {
+      format!("({} lines)", answer.lines().count())
+    }
+    (Some(answer), None) => answer.clone(),
+    (None, None) => String::new(),
+  };
+
+  let rows = records
+    .iter()
+    .map(|r| {
+      let before = previous.and_then(|records| {
+        records
+          .iter()
+          .find(|p| p.dataset == r.dataset && p.phase == r.phase)
+      });
+      let was = match before {
+        Some(p) if (&p.answer, &p.error) != (&r.answer, &r.error) => format!("was {}", answer(p)),
+        _ => String::new(),
+      };
+      let change = match before {
+        Some(p) if p.nanos > 0 => {
+          format!("{:+.1}%", (r.nanos as f64 / p.nanos as f64 - 1.0) * 100.0)
+        }
+        _ => String::new(),
+      };
+      return vec![
+        r.dataset.clone(),
+        r.phase.replace("part", "part "),
+        answer(r),
+        was,
+        aoc::Duration(std::time::Duration::from_nanos(r.nanos as u64)).to_string(),
+        change,
+      ];
+    })
+    .collect::<Vec<_>>();
+  print_rows(&["dataset", "phase", "answer", "", "time", "change"], &rows);
+
+  for r in records {
+    let phase = r.phase.replace("part", "part ");
+    if let Some(e) = &r.error {
+      println!("\n{} {phase}: {e}", r.dataset);
+    } else if let Some(answer) = r.answer.as_ref().filter(|a| a.contains('\n')) {
+      println!("\n{} {phase}:\n{answer}", r.dataset);
+    }
+  }
+}
+
+/// Parses `7`, `1,3,5`, `1..=19`, `1..19` or `all` into registered days.
+fn parse_days(spec: &str) -> Result<Vec<&'static Day>, String> {
+  if spec == "all" {
+    return Ok(days::DAYS.iter().collect());
+  }
+
+  return parse_numbers(spec)?
+    .iter()
+    .map(|&n| days::find(n).ok_or(format!("day {n} is not registered")))
+    .collect();
+}
+
+/// Parses `7`, `1,3,5`, `1..=19` or `1..19`.
+fn parse_numbers(spec: &str) -> Result<Vec<u8>, String> {
+  let number = |n: &str| {
+    n.parse::<u8>()
+      .map_err(|_| format!("invalid day `{n}` in `{spec}`"))
+  };
+
+  let mut numbers = vec![];
+  for part in spec.split(',') {
+    if let Some((from, to)) = part.split_once("..=") {
+      numbers.extend(number(from)?..=number(to)?);
+    } else if let Some((from, to)) = part.split_once("..") {
+      numbers.extend(number(from)?..number(to)?);
+    } else {
+      numbers.push(number(part)?);
+    }
+  }
+
+  return Ok(numbers);
+}
+
+fn print_table(summaries: &[(String, Summary)]) {
+  let part = |part: &Option<Answer>| match part {
+    Some((Ok(answer), duration)) if answer.contains('\n') => [
+      format!("({} lines)", answer.lines().count()),
+      duration.to_string(),
+    ],
+    Some((Ok(answer), duration)) => [answer.clone(), duration.to_string()],
+    Some((Err(_), duration)) => ["error".to_string(), duration.to_string()],
+    None => ["-".to_string(), "-".to_string()],
+  };
+
+  let rows = summaries
+    .iter()
+    .map(|(day, summary)| {
+      let [a1, t1] = part(&summary.part1);
+      let [a2, t2] = part(&summary.part2);
+      return vec![
+        day.clone(),
+        summary.source.name(),
+        match summary.error {
+          Some(_) => "error".to_string(),
+          None => summary.read.to_string(),
+        },
+        a1,
+        t1,
+        a2,
+        t2,
+      ];
+    })
+    .collect::<Vec<_>>();
+
+  print_rows(
+    &["day", "dataset", "read", "part 1", "time", "part 2", "time"],
+    &rows,
+  );
+}
+
+fn print_memory(summaries: &[(String, Summary)]) {
+  let rows = summaries
+    .iter()
+    .flat_map(|(day, summary)| {
+      summary.memory.iter().map(move |(phase, memory)| {
+        vec![
+          day.clone(),
+          summary.source.name(),
+          phase.to_string(),
+          memory.allocations.to_string(),
+          Bytes(memory.bytes).to_string(),
+          Bytes(memory.peak).to_string(),
+        ]
+      })
+    })
+    .collect::<Vec<_>>();
+
+  print_rows(
+    &[
+      "day",
+      "dataset",
+      "phase",
+      "allocations",
+      "allocated",
+      "peak",
+    ],
+    &rows,
+  );
+}
+
+fn print_benchmarks(benchmarks: &[(String, Benchmark)]) {
+  let rows = benchmarks
+    .iter()
+    .flat_map(|(day, benchmark)| {
+      benchmark.phases().into_iter().map(move |(phase, stats)| {
+        vec![
+          day.clone(),
+          benchmark.source.name(),
+          phase.to_string(),
+          stats.min.to_string(),
+          stats.median.to_string(),
+          stats.mean.to_string(),
+          stats.p95.to_string(),
+          stats.std_dev.to_string(),
+        ]
+      })
+    })
+    .collect::<Vec<_>>();
+
+  print_rows(
+    &[
+      "day", "dataset", "phase", "min", "median", "mean", "p95", "std dev",
+    ],
+    &rows,
+  );
+}
+
+fn print_comparisons(comparisons: &[(String, String, Comparison)]) {
+  let rows = comparisons
+    .iter()
+    .map(|(day, dataset, c)| {
+      vec![
+        day.clone(),
+        dataset.clone(),
+        c.phase.to_string(),
+        c.baseline.to_string(),
+        c.current.to_string(),
+        format!("{:+.1}%", c.change),
+        if c.regressed { "SLOWER" } else { "" }.to_string(),
+      ]
+    })
+    .collect::<Vec<_>>();
+
+  print_rows(
+    &[
+      "day", "dataset", "phase", "baseline", "median", "change", "",
+    ],
+    &rows,
+  );
+}
+
+/// Prints `rows` as left-aligned columns under `header`.
+fn print_rows(header: &[&str], rows: &[Vec<String>]) {
+  let header = header.iter().map(|h| h.to_string()).collect::<Vec<_>>();
+  let widths = (0..header.len())
+    .map(|i| {
+      [&header]
+        .into_iter()
+        .chain(rows)
+        .map(|row| row[i].chars().count())
+        .max()
+        .unwrap()
+    })
+    .collect::<Vec<_>>();
+
+  for row in [&header].into_iter().chain(rows) {
+    let line = row
+      .iter()
+      .zip(&widths)
+      .map(|(cell, width)| format!("{cell:width$}"))
+      .collect::<Vec<_>>()
+      .join("  ");
+    println!("{}", line.trim_end());
+  }
+}
@@ -0,0 +1,234 @@
+use std::{
+  fmt::Display,
+  path::{Path, PathBuf},
+  thread,
+};
+
+use crate::Format;
+
+pub const USAGE: &str = "\
+options:
+  DATASET        name of a file under DIR/dayN/, without `.txt` (default: small input)
+  -p, --part     only run part 1 or part 2
+  -i, --input    read this file instead of the datasets, `-` reads stdin
+  -d, --data     root of the data directory (default: ./data)
+  -f, --format   how `aoc run` prints results: text, json or csv (default: text)
+  -j, --jobs     days and datasets to run at once, at most one per core. With
+                 more than 1 the timings aren't isolated, the days compete for
+                 the cores, `--jobs 1` times them one at a time. `aoc bench`
+                 always runs one at a time (default: number of cores, 1 with
+                 `alloc-stats`)
+  --warmup       untimed iterations before benchmarking (default: 3)
+  -n, --iterations
+                 timed iterations when benchmarking (default: 10)
+  --baseline     file with the benchmark baseline (default: DIR/baseline.tsv)
+  --save-baseline
+                 store the benchmark medians in the baseline
+  --threshold    percent a phase may get slower than the baseline (default: 10)
+  --endpoint     where `aoc fetch` downloads from (default: $AOC_ENDPOINT or
+                 https://adventofcode.com/2022)
+  -h, --help     print this message";
+
+/// Which half of the puzzle to run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+  One,
+  Two,
+}
+
+impl Display for Part {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    return match self {
+      Part::One => write!(f, "part 1"),
+      Part::Two => write!(f, "part 2"),
+    };
+  }
+}
+
+/// Where the puzzle input is read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+  /// A file named `{name}.txt` in the day's data directory, e.g. `small`.
+  Dataset(String),
+  /// A file given explicitly with `--input`.
+  File(PathBuf),
+  /// `--input -`.
+  Stdin,
+}
+
+impl Source {
+  pub fn name(&self) -> String {
+    return match self {
+      Source::Dataset(name) => name.clone(),
+      Source::File(path) => path.display().to_string(),
+      Source::Stdin => "stdin".to_string(),
+    };
+  }
+
+  /// Path handed over to the day's reader. `-` means stdin, see `aoc::lines`.
+  pub fn path(&self, data: &Path, day: &str) -> String {
+    return match self {
+      Source::Dataset(name) => data
+        .join(day)
+        .join(format!("{name}.txt"))
+        .display()
+        .to_string(),
+      Source::File(path) => path.display().to_string(),
+      Source::Stdin => "-".to_string(),
+    };
+  }
+
+  /// Where the known answers for this source live, see `Expected`.
+  pub fn expected_path(&self, data: &Path, day: &str) -> Option<PathBuf> {
+    return match self {
+      Source::Dataset(name) => Some(data.join(day).join(format!("{name}.expected"))),
+      Source::File(path) => Some(path.with_extension("expected")),
+      Source::Stdin => None,
+    };
+  }
+}
+
+#[derive(Clone, Debug)]
+pub struct Options {
+  pub sources: Vec<Source>,
+  /// Whether `sources` were named on the command line, rather than the
+  /// default `small` and `input`.
+  pub picked_sources: bool,
+  /// When `None` both parts are run.
+  pub part: Option<Part>,
+  pub data: PathBuf,
+  pub format: Format,
+  /// How many threads run days and datasets, from 1 to the number of cores.
+  pub jobs: usize,
+  /// Only used by `aoc bench`.
+  pub warmup: usize,
+  /// Only used by `aoc bench`, always at least 1.
+  pub iterations: usize,
+  /// Only used by `aoc bench`, see `Options::baseline_path`.
+  pub baseline: Option<PathBuf>,
+  /// Only used by `aoc bench`.
+  pub save_baseline: bool,
+  /// Only used by `aoc bench`, in percent.
+  pub threshold: f64,
+  /// Only used by `aoc fetch`, see `aoc::ENDPOINT`.
+  pub endpoint: Option<String>,
+}
+
+impl Default for Options {
+  fn default() -> Self {
+    return Options {
+      sources: vec![
+        Source::Dataset("small".to_string()),
+        Source::Dataset("input".to_string()),
+      ],
+      picked_sources: false,
+      part: None,
+      data: PathBuf::from("./data"),
+      format: Format::Text,
+      // The allocation counters are shared by all threads.
+      jobs: if cfg!(feature = "alloc-stats") {
+        1
+      } else {
+        thread::available_parallelism().map_or(1, |n| n.get())
+      },
+      warmup: 3,
+      iterations: 10,
+      baseline: None,
+      save_baseline: false,
+      threshold: 10.0,
+      endpoint: None,
+    };
+  }
+}
+
+impl Options {
+  pub fn parse<I>(args: I) -> Result<Self, String>
+  where
+    I: IntoIterator<Item = String>,
+  {
+    let mut options = Options::default();
+    let mut datasets = vec![];
+    let mut input = None;
+
+    let count = |flag: &str, value: String| {
+      value
+        .parse::<usize>()
+        .map_err(|_| format!("invalid value `{value}` for `{flag}`"))
+    };
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+      let mut value = |flag: &str| args.next().ok_or(format!("missing value for `{flag}`"));
+      match arg.as_str() {
+        "-p" | "--part" => {
+          options.part = match value(&arg)?.as_str() {
+            "1" => Some(Part::One),
+            "2" => Some(Part::Two),
+            other => return Err(format!("invalid part `{other}`, expected 1 or 2")),
+          }
+        }
+        "-i" | "--input" => {
+          input = match value(&arg)?.as_str() {
+            "-" => Some(Source::Stdin),
+            path => Some(Source::File(PathBuf::from(path))),
+          }
+        }
+        "-d" | "--data" => options.data = PathBuf::from(value(&arg)?),
+        "-f" | "--format" => options.format = value(&arg)?.parse()?,
+        "-j" | "--jobs" => match count(&arg, value(&arg)?)? {
+          0 => return Err(format!("`{arg}` must be at least 1")),
+          // The memory of each phase would include what the others allocate.
+          n if n > 1 && cfg!(feature = "alloc-stats") => {
+            return Err(format!(
+              "`{arg}` must be 1 with `alloc-stats`, the allocation counters are shared by all threads"
+            ))
+          }
+          // More threads than cores would only take turns.
+          n => {
+            let cores = thread::available_parallelism().map_or(1, |n| n.get());
+            options.jobs = n.min(cores);
+          }
+        },
+        "--warmup" => options.warmup = count(&arg, value(&arg)?)?,
+        "-n" | "--iterations" => match count(&arg, value(&arg)?)? {
+          0 => return Err(format!("`{arg}` must be at least 1")),
+          n => options.iterations = n,
+        },
+        "--baseline" => options.baseline = Some(PathBuf::from(value(&arg)?)),
+        "--save-baseline" => options.save_baseline = true,
+        "--threshold" => {
+          let threshold = value(&arg)?;
+          options.threshold = match threshold.parse::<f64>() {
+            Ok(percent) if percent.is_finite() && percent >= 0.0 => percent,
+            _ => return Err(format!("invalid value `{threshold}` for `{arg}`")),
+          };
+        }
+        "--endpoint" => options.endpoint = Some(value(&arg)?),
+        "-h" | "--help" => return Err(USAGE.to_string()),
+        flag if flag.starts_with('-') => return Err(format!("unknown option `{flag}`")),
+        dataset => datasets.push(Source::Dataset(dataset.to_string())),
+      }
+    }
+
+    match (input, datasets.is_empty()) {
+      (Some(_), false) => return Err("`--input` can't be combined with datasets".to_string()),
+      (Some(input), true) => options.sources = vec![input],
+      (None, false) => options.sources = datasets,
+      (None, true) => return Ok(options),
+    }
+    options.picked_sources = true;
+
+    return Ok(options);
+  }
+
+  pub fn baseline_path(&self) -> PathBuf {
+    return match &self.baseline {
+      Some(path) => path.clone(),
+      None => self.data.join("baseline.tsv"),
+    };
+  }
+
+  pub fn runs(&self, part: Part) -> bool {
+    return self.part.is_none_or(|p| p == part);
+  }
+}
@@ -0,0 +1,223 @@
+use std::{fmt::Write, str::FromStr};
+
+use crate::{Answer, Memory, Summary};
+
+/// How `aoc run` prints the results.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+  /// Answers as they come, followed by a summary table.
+  Text,
+  /// A JSON array of `Record`s, one per line.
+  Json,
+  /// A header followed by one line per `Record`.
+  Csv,
+}
+
+impl FromStr for Format {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    return match s {
+      "text" => Ok(Format::Text),
+      "json" => Ok(Format::Json),
+      "csv" => Ok(Format::Csv),
+      _ => Err(format!("invalid format `{s}`, expected text, json or csv")),
+    };
+  }
+}
+
+/// A single phase of a day, as printed by the machine readable formats.
+pub struct Record {
+  pub day: String,
+  pub dataset: String,
+  /// `read`, `part1` or `part2`.
+  pub phase: &'static str,
+  /// `None` for `read`, or when the phase failed.
+  pub answer: Option<String>,
+  pub error: Option<String>,
+  pub nanos: u128,
+  /// Only with the `alloc-stats` feature.
+  pub memory: Option<Memory>,
+}
+
+impl Record {
+  pub fn from_summary(day: &str, summary: &Summary) -> Vec<Self> {
+    let read = match &summary.error {
+      None => Some(("read", "read", Ok(None), summary.read)),
+      Some(e) => Some(("read", "read", Err(e.to_string()), summary.read)),
+    };
+    let part = |phase, name, part: &Option<Answer>| {
+      let (answer, duration) = part.as_ref()?;
+      let answer = match answer {
+        Ok(answer) => Ok(Some(answer.clone())),
+        Err(e) => Err(e.to_string()),
+      };
+      return Some((phase, name, answer, *duration));
+    };
+
+    return [
+      read,
+      part("part1", "part 1", &summary.part1),
+      part("part2", "part 2", &summary.part2),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|(phase, name, answer, duration)| Record {
+      day: day.to_string(),
+      dataset: summary.source.name(),
+      phase,
+      answer: answer.clone().ok().flatten(),
+      error: answer.err(),
+      nanos: duration.0.as_nanos(),
+      memory: summary
+        .memory
+        .iter()
+        .find(|(phase, _)| *phase == name)
+        .map(|&(_, memory)| memory),
+    })
+    .collect();
+  }
+}
+
+pub fn to_json(records: &[Record]) -> String {
+  let lines = records
+    .iter()
+    .map(|r| {
+      let optional = |s: &Option<String>| match s {
+        Some(s) => json_string(s),
+        None => "null".to_string(),
+      };
+      let memory = |f: fn(&Memory) -> usize| match &r.memory {
+        Some(memory) => f(memory).to_string(),
+        None => "null".to_string(),
+      };
+      return format!(
+        "  {{\"day\": {}, \"dataset\": {}, \"phase\": {}, \"answer\": {}, \"error\": {}, \"nanos\": {}, \"allocations\": {}, \"bytes\": {}, \"peak\": {}}}",
+        json_string(&r.day),
+        json_string(&r.dataset),
+        json_string(r.phase),
+        optional(&r.answer),
+        optional(&r.error),
+        r.nanos,
+        memory(|m| m.allocations),
+        memory(|m| m.bytes),
+        memory(|m| m.peak)
+      );
+    })
+    .collect::<Vec<_>>();
+
+  if lines.is_empty() {
+    return "[]\n".to_string();
+  }
+  return format!("[\n{}\n]\n", lines.join(",\n"));
+}
+
+pub fn to_csv(records: &[Record]) -> String {
+  let mut csv = "day,dataset,phase,answer,error,nanos,allocations,bytes,peak\n".to_string();
+  for r in records {
+    let answer = r.answer.as_deref().unwrap_or("");
+    let error = r.error.as_deref().unwrap_or("");
+    let fields = [r.day.as_str(), r.dataset.as_str(), r.phase, answer, error].map(csv_field);
+    let memory = match &r.memory {
+      Some(m) => format!("{},{},{}", m.allocations, m.bytes, m.peak),
+      None => ",,".to_string(),
+    };
+    writeln!(csv, "{},{},{memory}", fields.join(","), r.nanos).unwrap();
+  }
+  return csv;
+}
+
+/// Reads back what `to_csv` wrote, e.g. to compare the output of two runs.
+pub fn from_csv(csv: &str) -> Result<Vec<Record>, String> {
+  let mut rows = csv_rows(csv).into_iter();
+  let header = rows.next().unwrap_or_default();
+  if header.join(",") != "day,dataset,phase,answer,error,nanos,allocations,bytes,peak" {
+    return Err(format!("unexpected CSV header `{}`", header.join(",")));
+  }
+
+  return rows
+    .map(|row| {
+      let invalid = || format!("invalid CSV record `{}`", row.join(","));
+      let [day, dataset, phase, answer, error, nanos, allocations, bytes, peak] =
+        <[String; 9]>::try_from(row.clone()).map_err(|_| invalid())?;
+      let optional = |s: String| if s.is_empty() { None } else { Some(s) };
+      let memory = match [allocations, bytes, peak].map(|n| n.parse::<usize>()) {
+        [Ok(allocations), Ok(bytes), Ok(peak)] => Some(Memory {
+          allocations,
+          bytes,
+          peak,
+        }),
+        _ => None,
+      };
+      return Ok(Record {
+        day,
+        dataset,
+        phase: ["read", "part1", "part2"]
+          .into_iter()
+          .find(|&p| p == phase)
+          .ok_or_else(invalid)?,
+        answer: optional(answer),
+        error: optional(error),
+        nanos: nanos.parse().map_err(|_| invalid())?,
+        memory,
+      });
+    })
+    .collect();
+}
+
+/// Splits CSV into rows of unquoted fields (RFC 4180).
+fn csv_rows(csv: &str) -> Vec<Vec<String>> {
+  let mut rows = vec![];
+  let (mut row, mut field) = (vec![], String::new());
+  let mut chars = csv.chars().peekable();
+  let mut quoted = false;
+  while let Some(c) = chars.next() {
+    match (quoted, c) {
+      (true, '"') if chars.peek() == Some(&'"') => {
+        chars.next();
+        field.push('"');
+      }
+      (true, '"') => quoted = false,
+      (true, c) => field.push(c),
+      (false, '"') => quoted = true,
+      (false, ',') => row.push(std::mem::take(&mut field)),
+      (false, '\r') => (),
+      (false, '\n') => {
+        row.push(std::mem::take(&mut field));
+        rows.push(std::mem::take(&mut row));
+      }
+      (false, c) => field.push(c),
+    }
+  }
+  if !field.is_empty() || !row.is_empty() {
+    row.push(field);
+    rows.push(row);
+  }
+  return rows;
+}
+
+fn json_string(s: &str) -> String {
+  let mut escaped = String::with_capacity(s.len() + 2);
+  escaped.push('"');
+  for c in s.chars() {
+    match c {
+      '"' => escaped.push_str("\\\""),
+      '\\' => escaped.push_str("\\\\"),
+      '\n' => escaped.push_str("\\n"),
+      '\r' => escaped.push_str("\\r"),
+      '\t' => escaped.push_str("\\t"),
+      c if c.is_control() => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+      c => escaped.push(c),
+    }
+  }
+  escaped.push('"');
+  return escaped;
+}
+
+/// Quotes the field when it has commas, quotes or line breaks (RFC 4180).
+fn csv_field(s: &str) -> String {
+  if s.contains([',', '"', '\n', '\r']) {
+    return format!("\"{}\"", s.replace('"', "\"\""));
+  }
+  return s.to_string();
+}
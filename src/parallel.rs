@@ -0,0 +1,82 @@
+use std::{
+  any::Any,
+  collections::HashMap,
+  panic::{self, AssertUnwindSafe},
+  sync::{
+    atomic::{AtomicUsize, Ordering},
+    mpsc,
+  },
+  thread,
+};
+
+/// Runs `tasks` on `jobs` threads, handing the results to `report` in the
+/// order of `tasks` as soon as they and all the ones before them are done.
+///
+/// With a single job the tasks run on the calling thread. Otherwise every
+/// thread pulls the next task once it's done with the last one. They are
+/// plain threads rather than a rayon pool, so tasks using rayon themselves
+/// (like day19) still get the global pool with every core. The tasks compete
+/// for the cores, which shows in their timings.
+///
+/// A task that panics is reported as `Err` with the panic message, the other
+/// ones still run.
+pub fn run_ordered<T, R>(
+  jobs: usize,
+  tasks: &[T],
+  run: impl Fn(&T) -> R + Sync,
+  mut report: impl FnMut(&T, Result<R, String>),
+) -> Result<(), String>
+where
+  T: Sync,
+  R: Send,
+{
+  let run_one = |task| panic::catch_unwind(AssertUnwindSafe(|| run(task))).map_err(panic_message);
+  if jobs <= 1 {
+    for task in tasks {
+      report(task, run_one(task));
+    }
+    return Ok(());
+  }
+
+  let next = AtomicUsize::new(0);
+  let (sender, receiver) = mpsc::channel();
+  return thread::scope(|scope| {
+    for i in 0..jobs.min(tasks.len()) {
+      let (next, run_one, sender) = (&next, &run_one, sender.clone());
+      thread::Builder::new()
+        .name(format!("aoc-{i}"))
+        .spawn_scoped(scope, move || loop {
+          let i = next.fetch_add(1, Ordering::Relaxed);
+          if i >= tasks.len() {
+            return;
+          }
+          // The receiver is only gone when `report` panicked.
+          let _ = sender.send((i, run_one(&tasks[i])));
+        })
+        .map_err(|e| e.to_string())?;
+    }
+    // Only the threads hold a sender now, the loop ends when they are done.
+    drop(sender);
+
+    let mut done = HashMap::new();
+    let mut reported = 0;
+    for (i, result) in receiver {
+      done.insert(i, result);
+      while let Some(result) = done.remove(&reported) {
+        report(&tasks[reported], result);
+        reported += 1;
+      }
+    }
+    return Ok(());
+  });
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+  if let Some(message) = payload.downcast_ref::<&str>() {
+    return message.to_string();
+  }
+  return match payload.downcast::<String>() {
+    Ok(message) => *message,
+    Err(_) => "panicked".to_string(),
+  };
+}
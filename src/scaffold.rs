@@ -0,0 +1,139 @@
+use std::{
+  fs, io,
+  path::{Path, PathBuf},
+};
+
+const TEMPLATE: &str = "\
+use crate::{self as aoc, Error, Lines, Result};
+
+pub fn solution() -> Box<dyn aoc::Solution> {
+  return aoc::solve(DayN);
+}
+
+struct DayN;
+
+impl aoc::Solver for DayN {
+  type Input = Vec<String>;
+  type Output1 = usize;
+  type Output2 = usize;
+
+  fn parse(&self, input: Lines) -> Result<Vec<String>> {
+    let lines = input.all()?;
+    return Ok(lines.iter().map(|line| line.to_string()).collect());
+  }
+
+  fn part1(&self, _data: &Vec<String>) -> Result<usize> {
+    return Err(Error::new(\"not solved yet\"));
+  }
+
+  fn part2(&self, _data: &Vec<String>) -> Result<usize> {
+    return Err(Error::new(\"not solved yet\"));
+  }
+}
+";
+
+/// Creates `src/days/dayN.rs` from a template, registers it in
+/// `src/days/mod.rs`, and adds empty `small.txt` and `input.txt` datasets.
+///
+/// `root` is the root of the repository. Returns the files it wrote. Until the
+/// answers of `small` are recorded, its tests are ignored (see `build.rs`).
+pub fn scaffold(root: &Path, data: &Path, day: u8) -> io::Result<Vec<PathBuf>> {
+  if !(1..=25).contains(&day) {
+    return Err(io::Error::new(
+      io::ErrorKind::InvalidInput,
+      format!("there is no day {day}, the puzzles go from 1 to 25"),
+    ));
+  }
+  let name = format!("day{day}");
+  let days = root.join("src").join("days");
+  let module = days.join(format!("{name}.rs"));
+  let registry = days.join("mod.rs");
+  if !registry.exists() {
+    return Err(io::Error::new(
+      io::ErrorKind::NotFound,
+      format!(
+        "{} doesn't exist, run it from the root of the repository",
+        registry.display()
+      ),
+    ));
+  }
+  if module.exists() {
+    return Err(io::Error::new(
+      io::ErrorKind::AlreadyExists,
+      format!("{} already exists", module.display()),
+    ));
+  }
+
+  let registered = register(&fs::read_to_string(&registry)?, day)?;
+  fs::write(&module, TEMPLATE.replace("DayN", &format!("Day{day}")))?;
+  fs::write(&registry, registered)?;
+  let mut written = vec![module, registry];
+
+  let dir = data.join(&name);
+  fs::create_dir_all(&dir)?;
+  for dataset in ["small.txt", "input.txt"] {
+    let path = dir.join(dataset);
+    if !path.exists() {
+      fs::write(&path, "")?;
+      written.push(path);
+    }
+  }
+  return Ok(written);
+}
+
+/// Adds `pub mod dayN;` and its `Day::new` entry to the contents of
+/// `src/days/mod.rs`, keeping both sorted.
+fn register(registry: &str, day: u8) -> io::Result<String> {
+  let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message.to_string());
+  let lines = registry.lines().collect::<Vec<_>>();
+
+  let modules = lines
+    .iter()
+    .position(|line| line.starts_with("pub mod "))
+    .ok_or_else(|| invalid("no `pub mod` in src/days/mod.rs"))?;
+  let modules = modules
+    ..lines[modules..]
+      .iter()
+      .position(|line| !line.starts_with("pub mod "))
+      .map_or(lines.len(), |end| modules + end);
+  let mut module_lines = lines[modules.clone()].to_vec();
+  let module = format!("pub mod day{day};");
+  if module_lines.contains(&module.as_str()) {
+    return Err(io::Error::new(
+      io::ErrorKind::AlreadyExists,
+      format!("day{day} is already registered in src/days/mod.rs"),
+    ));
+  }
+  module_lines.push(&module);
+  // The same order `rustfmt` keeps them in.
+  module_lines.sort_unstable_by_key(|line| line.trim_end_matches(';'));
+
+  let entries = lines
+    .iter()
+    .position(|line| line.starts_with("pub const DAYS"))
+    .ok_or_else(|| invalid("no `DAYS` in src/days/mod.rs"))?
+    + 1;
+  let entries = entries
+    ..lines[entries..]
+      .iter()
+      .position(|line| line.trim() == "];")
+      .map(|end| entries + end)
+      .ok_or_else(|| invalid("`DAYS` doesn't end with `];` in src/days/mod.rs"))?;
+  let mut entry_lines = lines[entries.clone()].to_vec();
+  let entry = format!("  Day::new({day}, day{day}::solution),");
+  entry_lines.push(&entry);
+  entry_lines.sort_by_key(|line| {
+    let number = line.trim().strip_prefix("Day::new(")?.split(',').next()?;
+    return number.parse::<u8>().ok();
+  });
+
+  let registered = [
+    &lines[..modules.start],
+    &module_lines,
+    &lines[modules.end..entries.start],
+    &entry_lines,
+    &lines[entries.end..],
+  ]
+  .concat();
+  return Ok(registered.join("\n") + "\n");
+}
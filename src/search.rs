@@ -0,0 +1,169 @@
+use std::{
+  cmp::Reverse,
+  collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+  hash::Hash,
+  ops::Add,
+};
+
+/// The cheapest way from one of the starts to one of the goals.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Route<N, C> {
+  /// Steps for `bfs`, the sum of the step costs otherwise.
+  pub cost: C,
+  /// From the start to the goal, both included.
+  pub nodes: Vec<N>,
+}
+
+/// Marks the starts in `Visited`, which have no parent.
+const START: usize = usize::MAX;
+
+/// Every node reached so far with the index of the one it was reached from,
+/// so that routes can be rebuilt without storing them.
+struct Visited<N>(Vec<(N, usize)>);
+
+impl<N: Clone> Visited<N> {
+  fn push(&mut self, node: N, parent: usize) -> usize {
+    self.0.push((node, parent));
+    return self.0.len() - 1;
+  }
+
+  fn node(&self, i: usize) -> &N {
+    return &self.0[i].0;
+  }
+
+  fn route<C>(&self, mut i: usize, cost: C) -> Route<N, C> {
+    let mut nodes = vec![];
+    while i != START {
+      nodes.push(self.0[i].0.clone());
+      i = self.0[i].1;
+    }
+    nodes.reverse();
+    return Route { cost, nodes };
+  }
+}
+
+/// Breadth-first search, every step costs 1. `None` when no goal is reachable.
+pub fn bfs<N, I>(
+  starts: impl IntoIterator<Item = N>,
+  mut neighbours: impl FnMut(&N) -> I,
+  mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Route<N, usize>>
+where
+  N: Clone + Eq + Hash,
+  I: IntoIterator<Item = N>,
+{
+  let mut seen = HashSet::new();
+  let mut visited = Visited(vec![]);
+  let mut queue = VecDeque::new();
+  for start in starts {
+    if seen.insert(start.clone()) {
+      queue.push_back((visited.push(start, START), 0));
+    }
+  }
+
+  while let Some((i, steps)) = queue.pop_front() {
+    if is_goal(visited.node(i)) {
+      return Some(visited.route(i, steps));
+    }
+    for next in neighbours(visited.node(i)) {
+      if seen.insert(next.clone()) {
+        queue.push_back((visited.push(next, i), steps + 1));
+      }
+    }
+  }
+  return None;
+}
+
+/// How many steps away from the closest start every reachable node is, e.g.
+/// for a flood fill or the distances from a node to all the others.
+pub fn bfs_distances<N, I>(
+  starts: impl IntoIterator<Item = N>,
+  mut neighbours: impl FnMut(&N) -> I,
+) -> HashMap<N, usize>
+where
+  N: Clone + Eq + Hash,
+  I: IntoIterator<Item = N>,
+{
+  let mut distances = HashMap::new();
+  let mut queue = VecDeque::new();
+  for start in starts {
+    if let Entry::Vacant(entry) = distances.entry(start.clone()) {
+      entry.insert(0);
+      queue.push_back((start, 0));
+    }
+  }
+
+  while let Some((node, steps)) = queue.pop_front() {
+    for next in neighbours(&node) {
+      if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+        entry.insert(steps + 1);
+        queue.push_back((next, steps + 1));
+      }
+    }
+  }
+  return distances;
+}
+
+/// Cheapest route when steps cost differently, `neighbours` gives each next
+/// node with the cost of getting there. Costs can't be negative.
+pub fn dijkstra<N, C, I>(
+  starts: impl IntoIterator<Item = N>,
+  neighbours: impl FnMut(&N) -> I,
+  is_goal: impl FnMut(&N) -> bool,
+) -> Option<Route<N, C>>
+where
+  N: Clone + Eq + Hash,
+  C: Copy + Ord + Default + Add<Output = C>,
+  I: IntoIterator<Item = (N, C)>,
+{
+  return astar(starts, neighbours, |_| C::default(), is_goal);
+}
+
+/// `dijkstra` guided by `heuristic`, an estimate of the cost left to the
+/// closest goal. The route is the cheapest as long as it never overestimates.
+pub fn astar<N, C, I>(
+  starts: impl IntoIterator<Item = N>,
+  mut neighbours: impl FnMut(&N) -> I,
+  mut heuristic: impl FnMut(&N) -> C,
+  mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Route<N, C>>
+where
+  N: Clone + Eq + Hash,
+  C: Copy + Ord + Default + Add<Output = C>,
+  I: IntoIterator<Item = (N, C)>,
+{
+  // The cheapest cost found so far for each node, and where it's in `visited`.
+  let mut best: HashMap<N, (C, usize)> = HashMap::new();
+  let mut visited = Visited(vec![]);
+  let mut heap = BinaryHeap::new();
+  for start in starts {
+    if let Entry::Vacant(entry) = best.entry(start.clone()) {
+      let estimate = heuristic(&start);
+      let i = visited.push(start, START);
+      entry.insert((C::default(), i));
+      heap.push(Reverse((estimate, C::default(), i)));
+    }
+  }
+
+  while let Some(Reverse((_, cost, i))) = heap.pop() {
+    // A cheaper way to this node was found after this one was queued.
+    if best[visited.node(i)].1 != i {
+      continue;
+    }
+    if is_goal(visited.node(i)) {
+      return Some(visited.route(i, cost));
+    }
+
+    for (next, step) in neighbours(visited.node(i)) {
+      let cost = cost + step;
+      if matches!(best.get(&next), Some(&(known, _)) if known <= cost) {
+        continue;
+      }
+      let estimate = cost + heuristic(&next);
+      let j = visited.push(next.clone(), i);
+      best.insert(next, (cost, j));
+      heap.push(Reverse((estimate, cost, j)));
+    }
+  }
+  return None;
+}
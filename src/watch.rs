@@ -0,0 +1,131 @@
+use std::{
+  collections::HashMap,
+  ffi::{CString, OsStr},
+  io,
+  mem::size_of,
+  os::unix::ffi::OsStrExt,
+  path::{Path, PathBuf},
+  time::Duration,
+};
+
+/// Which files of a watched directory matter, given their name.
+pub type Filter = Box<dyn Fn(&Path) -> bool>;
+
+/// Waits for files to change through Linux inotify.
+///
+/// Directories are watched rather than the files themselves, since editors
+/// often save by renaming a new file over the old one.
+pub struct Watcher {
+  fd: i32,
+  /// The directory and its filter, by watch descriptor.
+  watches: HashMap<i32, (PathBuf, Filter)>,
+}
+
+const EVENTS: u32 = libc::IN_CLOSE_WRITE | libc::IN_MOVED_TO | libc::IN_CREATE | libc::IN_DELETE;
+
+impl Watcher {
+  pub fn new() -> io::Result<Self> {
+    let fd = unsafe { libc::inotify_init1(libc::IN_CLOEXEC) };
+    if fd < 0 {
+      return Err(io::Error::last_os_error());
+    }
+    return Ok(Watcher {
+      fd,
+      watches: HashMap::new(),
+    });
+  }
+
+  /// Watches the files of `dir` for which `filter` is true, it gets their
+  /// path within `dir`.
+  pub fn add(&mut self, dir: &Path, filter: impl Fn(&Path) -> bool + 'static) -> io::Result<()> {
+    let path = CString::new(dir.as_os_str().as_bytes())?;
+    let wd = unsafe { libc::inotify_add_watch(self.fd, path.as_ptr(), EVENTS) };
+    if wd < 0 {
+      let e = io::Error::last_os_error();
+      return Err(io::Error::new(e.kind(), format!("{}: {e}", dir.display())));
+    }
+    self
+      .watches
+      .insert(wd, (dir.to_path_buf(), Box::new(filter)));
+    return Ok(());
+  }
+
+  /// Blocks until a watched file changes, then keeps collecting changes until
+  /// none came for `settle`, so a save touching several files (or a build)
+  /// is reported once. Returns the changed files, sorted.
+  pub fn wait(&self, settle: Duration) -> io::Result<Vec<PathBuf>> {
+    let mut changed = vec![];
+    while changed.is_empty() {
+      self.poll(None)?;
+      self.read(&mut changed)?;
+    }
+    while self.poll(Some(settle))? {
+      self.read(&mut changed)?;
+    }
+    changed.sort_unstable();
+    changed.dedup();
+    return Ok(changed);
+  }
+
+  /// Whether there are events to read before `timeout`, forever when `None`.
+  fn poll(&self, timeout: Option<Duration>) -> io::Result<bool> {
+    let mut fds = libc::pollfd {
+      fd: self.fd,
+      events: libc::POLLIN,
+      revents: 0,
+    };
+    let timeout = timeout.map_or(-1, |t| t.as_millis().min(i32::MAX as u128) as i32);
+    loop {
+      match unsafe { libc::poll(&mut fds, 1, timeout) } {
+        n if n >= 0 => return Ok(n > 0),
+        _ => {
+          let e = io::Error::last_os_error();
+          if e.kind() != io::ErrorKind::Interrupted {
+            return Err(e);
+          }
+        }
+      }
+    }
+  }
+
+  /// Reads the pending events, adding the paths that pass their filter.
+  fn read(&self, changed: &mut Vec<PathBuf>) -> io::Result<()> {
+    // Aligned for `inotify_event`, and room for plenty of them at once.
+    let mut buffer = vec![0u32; 4096];
+    let n = unsafe { libc::read(self.fd, buffer.as_mut_ptr().cast(), buffer.len() * 4) };
+    if n < 0 {
+      return Err(io::Error::last_os_error());
+    }
+    let bytes = unsafe { std::slice::from_raw_parts(buffer.as_ptr().cast::<u8>(), n as usize) };
+
+    let header = size_of::<libc::inotify_event>();
+    let mut offset = 0;
+    while offset + header <= bytes.len() {
+      let event = unsafe { &*bytes.as_ptr().add(offset).cast::<libc::inotify_event>() };
+      let name = &bytes[offset + header..offset + header + event.len as usize];
+      offset += header + event.len as usize;
+
+      if event.mask & libc::IN_Q_OVERFLOW != 0 {
+        // Events were lost, so anything may have changed.
+        changed.extend(self.watches.values().map(|(dir, _)| dir.clone()));
+        continue;
+      }
+      let Some((dir, filter)) = self.watches.get(&event.wd) else {
+        continue;
+      };
+      // The name is padded with NULs to keep the next event aligned.
+      let name = name.split(|&b| b == 0).next().unwrap_or_default();
+      let name = Path::new(OsStr::from_bytes(name));
+      if filter(name) {
+        changed.push(dir.join(name));
+      }
+    }
+    return Ok(());
+  }
+}
+
+impl Drop for Watcher {
+  fn drop(&mut self) {
+    unsafe { libc::close(self.fd) };
+  }
+}
@@ -0,0 +1,73 @@
+//! `aoc::Baseline` comparing and updating the medians of benchmarks.
+
+use std::{env, fs, time::Duration};
+
+use aoc::{Baseline, Benchmark, Source, Stats};
+
+/// A benchmark of `small` whose phases all took `read`, `part1` and `part2`
+/// nanoseconds, without part 2 when it's `None`.
+fn benchmark(read: u64, part1: u64, part2: Option<u64>) -> Benchmark {
+  let stats = |nanos| Stats::new(vec![Duration::from_nanos(nanos)]);
+  return Benchmark {
+    source: Source::Dataset("small".to_string()),
+    read: stats(read),
+    part1: Some(stats(part1)),
+    part2: part2.map(stats),
+  };
+}
+
+#[test]
+fn flags_phases_slower_than_the_threshold() {
+  let mut baseline = Baseline::default();
+  assert!(baseline
+    .compare("day1", &benchmark(100, 100, None), 10.0)
+    .is_empty());
+
+  baseline.update("day1", &benchmark(100, 100, None));
+  let comparisons = baseline.compare("day1", &benchmark(105, 120, Some(50)), 10.0);
+  // Part 2 isn't in the baseline yet.
+  let changes = comparisons
+    .iter()
+    .map(|c| (c.phase, c.change.round() as i64, c.regressed))
+    .collect::<Vec<_>>();
+  assert_eq!(changes, [("read", 5, false), ("part 1", 20, true)]);
+  assert!(baseline
+    .compare("day2", &benchmark(1, 1, None), 10.0)
+    .is_empty());
+}
+
+#[test]
+fn updates_only_the_phases_that_ran() {
+  let mut baseline = Baseline::default();
+  baseline.update("day1", &benchmark(100, 200, Some(300)));
+  baseline.update("day1", &benchmark(150, 250, None));
+
+  let comparisons = baseline.compare("day1", &benchmark(150, 250, Some(300)), 0.0);
+  assert_eq!(comparisons.len(), 3);
+  assert!(comparisons.iter().all(|c| c.change == 0.0 && !c.regressed));
+}
+
+#[test]
+fn writes_and_reads_back() {
+  let path = env::temp_dir().join(format!("aoc-baseline-{}.tsv", std::process::id()));
+  let mut baseline = Baseline::default();
+  baseline.update("day14", &benchmark(100, 200, Some(300)));
+  baseline.write(&path).unwrap();
+
+  let back = Baseline::read(&path).unwrap();
+  let comparisons = back.compare("day14", &benchmark(100, 200, Some(600)), 10.0);
+  let regressed = comparisons.iter().map(|c| c.regressed).collect::<Vec<_>>();
+  assert_eq!(regressed, [false, false, true]);
+
+  fs::write(&path, "# comment\nday14\tsmall\tread\n").unwrap();
+  let error = Baseline::read(&path).unwrap_err();
+  assert!(error
+    .to_string()
+    .ends_with(":2: expected day, dataset, phase and nanoseconds"));
+  fs::remove_file(&path).unwrap();
+
+  assert!(Baseline::read(&path)
+    .unwrap()
+    .compare("day14", &benchmark(1, 1, None), 0.0)
+    .is_empty());
+}
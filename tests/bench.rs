@@ -0,0 +1,40 @@
+//! `aoc::Stats` over samples with known statistics.
+
+use std::time::Duration;
+
+use aoc::Stats;
+
+/// Min, median, mean, p95 and standard deviation in nanoseconds.
+fn stats(nanos: &[u64]) -> [u128; 5] {
+  let samples = nanos.iter().map(|&n| Duration::from_nanos(n)).collect();
+  let stats = Stats::new(samples);
+  return [
+    stats.min,
+    stats.median,
+    stats.mean,
+    stats.p95,
+    stats.std_dev,
+  ]
+  .map(|d| d.0.as_nanos());
+}
+
+#[test]
+fn takes_the_middle_sample_of_an_odd_count() {
+  // The standard deviation is √(200 / 3) ≈ 8.2.
+  assert_eq!(stats(&[30, 10, 20]), [10, 20, 20, 30, 8]);
+  assert_eq!(stats(&[7]), [7, 7, 7, 7, 0]);
+}
+
+#[test]
+fn averages_the_two_middle_samples_of_an_even_count() {
+  // The standard deviation is √125 ≈ 11.2.
+  assert_eq!(stats(&[40, 10, 30, 20]), [10, 25, 25, 40, 11]);
+}
+
+#[test]
+fn ranks_the_95th_percentile() {
+  let nanos = (1..=20).rev().collect::<Vec<_>>();
+  assert_eq!(stats(&nanos)[3], 19);
+  let nanos = (1..=100).collect::<Vec<_>>();
+  assert_eq!(stats(&nanos)[3], 95);
+}
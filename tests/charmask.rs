@@ -0,0 +1,49 @@
+//! `aoc::CharMask` set algebra over its alphabets.
+
+use aoc::{Ascii, CharMask, Digits};
+
+#[test]
+fn combines_sets() {
+  let a: CharMask = "abcXY".chars().collect();
+  let b: CharMask = "cdY".chars().collect();
+  assert_eq!((a | b).iter().collect::<String>(), "abcdXY");
+  assert_eq!((a & b).iter().collect::<String>(), "cY");
+  assert_eq!((a - b).iter().collect::<String>(), "abX");
+  assert_eq!((a ^ b).iter().collect::<String>(), "abdX");
+  assert_eq!((a ^ b).len(), 4);
+  assert!((a & b).is_subset(&a));
+  assert!(!a.is_subset(&b));
+  assert_eq!((a - a).first(), None);
+}
+
+#[test]
+fn rejects_characters_outside_of_the_alphabet() {
+  let mut digits = CharMask::<Digits>::new();
+  assert_eq!(digits.insert('7'), Ok(true));
+  assert_eq!(digits.insert('7'), Ok(false));
+  assert_eq!(digits.insert('x'), Err('x'));
+  assert!(digits.contains('7') && !digits.contains('x'));
+  assert!(digits.remove('7'));
+  assert!(digits.is_empty());
+}
+
+#[test]
+fn spans_both_words() {
+  let ascii: CharMask<Ascii> = "~ !0aZ\n".chars().collect();
+  assert_eq!(ascii.len(), 7);
+  assert_eq!(ascii.first(), Some('\n'));
+  assert_eq!(ascii.iter().len(), 7);
+  assert_eq!(ascii.iter().last(), Some('~'));
+  assert_eq!(
+    format!("{ascii:?}"),
+    r#"{'\n', ' ', '!', '0', 'Z', 'a', '~'}"#
+  );
+}
+
+#[test]
+fn builds_from_strings_without_panicking() {
+  let digits = CharMask::<Digits>::try_from("2023").unwrap();
+  assert_eq!(digits.iter().collect::<String>(), "023");
+  assert_eq!(CharMask::<Digits>::try_from("20x3"), Err('x'));
+  assert_eq!(CharMask::<Digits>::try_from_iter("a1".chars()), Err('a'));
+}
@@ -0,0 +1,34 @@
+//! A test for every day, dataset and part with a `.txt` under `data/`, checked
+//! against its `.expected` file. They are generated by `build.rs`.
+
+use std::path::PathBuf;
+
+use aoc::{days, Expected, Options, Part, Source, Status};
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+
+fn check(day: u8, dataset: &str, part: Part) {
+  let day = days::find(day).expect("the day is not registered in `days::DAYS`");
+  let options = Options {
+    part: Some(part),
+    data: PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("data"),
+    ..Options::default()
+  };
+  let source = Source::Dataset(dataset.to_string());
+
+  let summary = (day.solution)().run(&day.name(), &source, &options);
+  if let Some(e) = summary.error {
+    panic!("{e}");
+  }
+
+  let path = source.expected_path(&options.data, &day.name()).unwrap();
+  let expected = Expected::read(&path).unwrap();
+  for (_, status) in expected.check(&summary) {
+    match status {
+      Status::Pass => (),
+      Status::Fail { expected, actual } => assert_eq!(actual, expected),
+      Status::Error(e) => panic!("{e}"),
+      Status::Missing => panic!("no expected answer in {}", path.display()),
+    }
+  }
+}
@@ -0,0 +1,86 @@
+//! `aoc::Expected` reading `.expected` files and checking answers against them.
+
+use std::{env, fs, path::PathBuf};
+
+use aoc::{Answer, Duration, Error, Expected, Part, Source, Status, Summary};
+
+fn file(name: &str, content: &str) -> PathBuf {
+  let path = env::temp_dir().join(format!("aoc-{name}-{}.expected", std::process::id()));
+  fs::write(&path, content).unwrap();
+  return path;
+}
+
+fn summary(part1: Option<Result<&str, &str>>, part2: Option<Result<&str, &str>>) -> Summary {
+  let answer = |part: Option<Result<&str, &str>>| -> Option<Answer> {
+    let answer = part?.map(String::from).map_err(Error::new);
+    return Some((answer, Duration(std::time::Duration::ZERO)));
+  };
+  return Summary {
+    source: Source::Dataset("small".to_string()),
+    read: Duration(std::time::Duration::ZERO),
+    error: None,
+    part1: answer(part1),
+    part2: answer(part2),
+    memory: vec![],
+  };
+}
+
+#[test]
+fn reads_multiline_answers() {
+  let path = file("multiline", "--- part 1\n13140\n--- part 2\n##..\n..##\n");
+  let expected = Expected::read(&path).unwrap();
+  assert_eq!(expected.get(Part::One), Some(&"13140".to_string()));
+  assert_eq!(expected.get(Part::Two), Some(&"##..\n..##".to_string()));
+
+  expected.write(&path).unwrap();
+  assert_eq!(Expected::read(&path).unwrap(), expected);
+  fs::remove_file(&path).unwrap();
+
+  // A file that isn't there yet knows nothing.
+  assert_eq!(Expected::read(&path).unwrap(), Expected::default());
+}
+
+#[test]
+fn rejects_unknown_parts() {
+  let path = file("unknown", "--- part 3\n1\n");
+  let error = Expected::read(&path).unwrap_err();
+  assert!(error.to_string().ends_with("unknown part `3`"));
+  fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn checks_and_updates_the_answers() {
+  let expected = Expected {
+    part1: Some("24000".to_string()),
+    part2: None,
+  };
+  assert_eq!(
+    expected.check(&summary(Some(Ok("24000")), Some(Ok("45000")))),
+    [(Part::One, Status::Pass), (Part::Two, Status::Missing)]
+  );
+  assert_eq!(
+    expected.check(&summary(Some(Ok("1")), None)),
+    [(
+      Part::One,
+      Status::Fail {
+        expected: "24000".to_string(),
+        actual: "1".to_string()
+      }
+    )]
+  );
+  assert_eq!(
+    expected.check(&summary(None, Some(Err("not solved yet")))),
+    [(Part::Two, Status::Error(Error::new("not solved yet")))]
+  );
+
+  // Part 1 didn't run, so its answer stays.
+  let mut updated = expected.clone();
+  updated.update(&summary(None, Some(Ok("45000"))));
+  assert_eq!(
+    updated,
+    Expected {
+      part1: Some("24000".to_string()),
+      part2: Some("45000".to_string()),
+    }
+  );
+}
@@ -0,0 +1,137 @@
+//! `aoc::Fetcher` against a local stand-in for the puzzle server.
+
+use std::{
+  env, fs,
+  io::{BufRead, BufReader, Write},
+  net::TcpListener,
+  path::PathBuf,
+  sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+  },
+  thread,
+  time::Duration,
+};
+
+use aoc::{Fetched, Fetcher};
+
+/// Serves `{day}\n` for `/day/{day}/input` to the `session=good` cookie,
+/// answers 400 to any other session like the real one, and 404 past day 19.
+/// Returns its endpoint and how many requests it got.
+fn server() -> (String, Arc<AtomicUsize>) {
+  let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+  let endpoint = format!("http://{}/2022", listener.local_addr().unwrap());
+  let requests = Arc::new(AtomicUsize::new(0));
+  let counter = requests.clone();
+
+  thread::spawn(move || {
+    for stream in listener.incoming() {
+      let mut stream = stream.unwrap();
+      counter.fetch_add(1, Ordering::SeqCst);
+      let request = BufReader::new(&stream)
+        .lines()
+        .map(|line| line.unwrap())
+        .take_while(|line| !line.is_empty())
+        .collect::<Vec<_>>();
+
+      let day = request[0]
+        .strip_prefix("GET /2022/day/")
+        .and_then(|rest| rest.split_once("/input"))
+        .and_then(|(day, _)| day.parse::<u8>().ok());
+      let logged_in = request.iter().any(|line| line == "Cookie: session=good");
+      let (status, body) = match day {
+        _ if !logged_in => ("400 Bad Request", "Please log in.".to_string()),
+        Some(day) if day <= 19 => ("200 OK", format!("{day}\n")),
+        _ => ("404 Not Found", "Not found.".to_string()),
+      };
+      let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+      );
+      stream.write_all(response.as_bytes()).unwrap();
+    }
+  });
+  return (endpoint, requests);
+}
+
+fn data(name: &str) -> PathBuf {
+  let data = env::temp_dir().join(format!("aoc-fetch-{name}-{}", std::process::id()));
+  let _ = fs::remove_dir_all(&data);
+  return data;
+}
+
+fn fetcher(endpoint: &str, session: &str) -> Fetcher {
+  let mut fetcher = Fetcher::new(endpoint, session);
+  fetcher.interval = Duration::ZERO;
+  return fetcher;
+}
+
+#[test]
+fn downloads_once_then_reads_the_cache() {
+  let (endpoint, requests) = server();
+  let data = data("cache");
+  let fetcher = fetcher(&endpoint, "good");
+
+  let path = data.join("day7").join("input.txt");
+  assert!(matches!(fetcher.fetch(&data, 7), Ok(Fetched::Downloaded(p)) if p == path));
+  assert_eq!(fs::read_to_string(&path).unwrap(), "7\n");
+  assert!(matches!(fetcher.fetch(&data, 7), Ok(Fetched::Cached(p)) if p == path));
+  assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+  fs::remove_dir_all(&data).unwrap();
+}
+
+#[test]
+fn refetches_the_empty_input_of_a_new_day() {
+  let (endpoint, _) = server();
+  let data = data("empty");
+  fs::create_dir_all(data.join("day3")).unwrap();
+  fs::write(data.join("day3").join("input.txt"), "").unwrap();
+
+  let fetched = fetcher(&endpoint, "good").fetch(&data, 3);
+  assert!(matches!(fetched, Ok(Fetched::Downloaded(_))));
+  assert_eq!(
+    fs::read_to_string(data.join("day3").join("input.txt")).unwrap(),
+    "3\n"
+  );
+
+  fs::remove_dir_all(&data).unwrap();
+}
+
+#[test]
+fn reports_an_expired_session() {
+  let (endpoint, _) = server();
+  let data = data("expired");
+
+  let error = fetcher(&endpoint, "stale").fetch(&data, 1).err().unwrap();
+  assert!(error.contains("probably expired"), "{error}");
+  assert!(!data.join("day1").join("input.txt").exists());
+
+  let _ = fs::remove_dir_all(&data);
+}
+
+#[test]
+fn reports_a_locked_day() {
+  let (endpoint, _) = server();
+  let data = data("locked");
+
+  let error = fetcher(&endpoint, "good").fetch(&data, 25).err().unwrap();
+  assert!(error.contains("not unlocked yet"), "{error}");
+
+  let _ = fs::remove_dir_all(&data);
+}
+
+#[test]
+fn waits_between_requests() {
+  let (endpoint, _) = server();
+  let data = data("wait");
+  let mut fetcher = fetcher(&endpoint, "good");
+  fetcher.interval = Duration::from_millis(300);
+
+  fetcher.fetch(&data, 1).unwrap();
+  let start = std::time::Instant::now();
+  fetcher.fetch(&data, 2).unwrap();
+  assert!(start.elapsed() >= Duration::from_millis(250));
+
+  fs::remove_dir_all(&data).unwrap();
+}
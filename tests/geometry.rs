@@ -0,0 +1,57 @@
+//! `aoc::Point2`, `aoc::Point3` and their bounds, distances and neighbourhoods.
+
+use std::collections::HashSet;
+
+use aoc::{Bounds2, Direction, Direction8, Point2, Point3};
+
+#[test]
+fn measures_distances() {
+  let (a, b) = (Point2::new(-2i32, 3), Point2::new(4, 1));
+  assert_eq!(a.manhattan(&b), 8);
+  assert_eq!(a.chebyshev(&b), 6);
+  assert_eq!(a + b, Point2::new(2, 4));
+  assert_eq!((b - a) * 2, Point2::new(12, -4));
+
+  let (a, b) = (Point3::new(1u8, 5, 2), Point3::new(3u8, 0, 2));
+  assert_eq!(a.manhattan(&b), 7);
+  assert_eq!(a.chebyshev(&b), 5);
+}
+
+#[test]
+fn turns_and_steps() {
+  assert_eq!(Direction::Up.turn_right(), Direction::Right);
+  assert_eq!(Direction::Up.turn_left(), Direction::Left);
+  assert_eq!(Direction::Left.reverse(), Direction::Right);
+  assert_eq!(Direction8::from(Direction::Down), Direction8::Down);
+
+  let p = Point2::new(5usize, 5);
+  assert_eq!(p.step(Direction::Up), Point2::new(5, 4));
+  assert_eq!(p.step(Direction8::DownLeft), Point2::new(4, 6));
+}
+
+#[test]
+fn lists_neighbourhoods() {
+  let p = Point2::new(0i32, 0);
+  assert_eq!(p.neighbours4().iter().collect::<HashSet<_>>().len(), 4);
+  assert!(p.neighbours8().iter().all(|n| n.chebyshev(&p) == 1));
+
+  let p = Point3::new(0i32, 0, 0);
+  assert!(p.neighbours6().iter().all(|n| n.manhattan(&p) == 1));
+  let around = p.neighbours26();
+  assert_eq!(around.iter().collect::<HashSet<_>>().len(), 26);
+  assert!(around.iter().all(|n| n.chebyshev(&p) == 1));
+}
+
+#[test]
+fn bounds_points() {
+  let points = [Point2::new(3, -1), Point2::new(-2, 4), Point2::new(0, 0)];
+  let bounds = Bounds2::from_points(points).unwrap();
+  assert_eq!(
+    (bounds.min, bounds.max),
+    (Point2::new(-2, -1), Point2::new(3, 4))
+  );
+  assert_eq!(bounds.size(), Point2::new(6, 6));
+  assert!(!bounds.contains(&Point2::new(4, 0)));
+  assert!(bounds.grow(1).contains(&Point2::new(4, 0)));
+  assert_eq!(Bounds2::<i32>::from_points([]), None);
+}
@@ -0,0 +1,83 @@
+//! `aoc::Grid` parsing, indexing, walking and reshaping small maps.
+
+use aoc::{Grid, Line, Point2};
+
+fn lines(map: &str) -> Vec<Line> {
+  return map
+    .lines()
+    .enumerate()
+    .map(|(i, line)| Line::new(i + 1, line))
+    .collect();
+}
+
+fn grid(map: &str) -> Grid<char> {
+  return Grid::parse(&lines(map), Some).unwrap();
+}
+
+#[test]
+fn parses_and_displays_a_map() {
+  let map = grid("abc\ndef\n");
+  assert_eq!((map.width(), map.height()), (3, 2));
+  assert_eq!(map[(2, 1)], 'f');
+  assert_eq!(map.get((3, 0)), None);
+  assert_eq!(map.to_string(), "abc\ndef\n");
+}
+
+#[test]
+fn points_at_bad_cells_and_ragged_rows() {
+  let error = Grid::parse(&lines("..#\n.x.\n"), |c| {
+    matches!(c, '.' | '#').then_some(c)
+  })
+  .unwrap_err();
+  assert_eq!((error.line, error.column), (2, 2));
+
+  let error = Grid::parse(&lines("...\n..\n"), Some).unwrap_err();
+  assert_eq!((error.line, error.column), (2, 3));
+}
+
+#[test]
+fn wraps_and_offsets() {
+  let map = grid("abc\ndef\n");
+  assert_eq!(*map.get_wrapping(-1, 2), 'c');
+  assert_eq!(map.offset((0, 0), (-1, 0)), None);
+  assert_eq!(map.offset((0, 0), (2, 1)), Some((2, 1)));
+}
+
+#[test]
+fn walks_neighbours() {
+  let map = grid("abc\ndef\n");
+  let around = |ps: Vec<(usize, usize)>| ps.iter().map(|&p| map[p]).collect::<String>();
+  assert_eq!(around(map.neighbours4((1, 0)).collect()), "cea");
+  assert_eq!(around(map.neighbours8((1, 0)).collect()), "cfeda");
+}
+
+#[test]
+fn takes_points_like_tuples() {
+  let mut map = grid("abc\ndef\n");
+  assert_eq!(map[Point2::new(2, 1)], 'f');
+  map[Point2::new(0, 1)] = 'x';
+  assert_eq!(map.get(Point2::new(0, 1)), Some(&'x'));
+  assert_eq!(
+    map.neighbours4(Point2::new(1, 0)).collect::<Vec<_>>(),
+    [Point2::new(2, 0), Point2::new(1, 1), Point2::new(0, 0)]
+  );
+}
+
+#[test]
+fn views_rows_columns_and_diagonals() {
+  let map = grid("abc\ndef\nghi\n");
+  assert_eq!(map.row(1), ['d', 'e', 'f']);
+  assert_eq!(map.column(2).rev().collect::<String>(), "ifc");
+  assert_eq!(map.diagonal((1, 0)).collect::<String>(), "bf");
+  assert_eq!(map.anti_diagonal((2, 0)).collect::<String>(), "ceg");
+  assert_eq!(map.find(|&c| c == 'h'), Some((1, 2)));
+}
+
+#[test]
+fn transposes_and_rotates() {
+  let map = grid("abc\ndef\n");
+  assert_eq!(map.transpose().to_string(), "ad\nbe\ncf\n");
+  assert_eq!(map.rotate_right().to_string(), "da\neb\nfc\n");
+  assert_eq!(map.rotate_left().to_string(), "cf\nbe\nad\n");
+  assert_eq!(map.rotate_right().rotate_left(), map);
+}
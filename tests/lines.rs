@@ -0,0 +1,74 @@
+//! `aoc::Lines` line endings, blank lines, groups and line numbers.
+
+use std::io::Cursor;
+
+use aoc::{Line, Lines};
+
+fn lines(input: &'static str) -> Lines {
+  return Lines::new(Cursor::new(input));
+}
+
+/// The line numbers and texts of each group.
+fn groups(input: &'static str) -> Vec<Vec<(usize, String)>> {
+  return lines(input)
+    .groups()
+    .map(|group| {
+      let group = group.unwrap().into_iter();
+      return group.map(|line| (line.number, line.text)).collect();
+    })
+    .collect();
+}
+
+#[test]
+fn strips_line_endings() {
+  let read = lines("a\r\nb\n\r\nc").collect::<aoc::Result<Vec<_>>>();
+  assert_eq!(
+    read,
+    Ok(vec![
+      Line::new(1, "a"),
+      Line::new(2, "b"),
+      Line::new(3, ""),
+      Line::new(4, "c"),
+    ])
+  );
+}
+
+#[test]
+fn splits_groups_on_any_blank_lines() {
+  let input = "\n  \n1\n2\n\n \t\n\n3\r\n\r\n4\n\n";
+  let pair = |number: usize, text: &str| (number, text.to_string());
+  assert_eq!(
+    groups(input),
+    [
+      vec![pair(3, "1"), pair(4, "2")],
+      vec![pair(8, "3")],
+      vec![pair(10, "4")],
+    ]
+  );
+  assert!(groups("\n\n").is_empty());
+}
+
+#[test]
+fn reads_all_without_trailing_blank_lines() {
+  assert_eq!(
+    lines("\n1\n\n2\n  \n\n").all(),
+    Ok(vec![
+      Line::new(1, ""),
+      Line::new(2, "1"),
+      Line::new(3, ""),
+      Line::new(4, "2"),
+    ])
+  );
+  // Days can always look at the first line, even of an empty input.
+  assert_eq!(lines("").all(), Ok(vec![Line::new(1, "")]));
+  assert_eq!(lines("\n \n").all(), Ok(vec![Line::new(1, "")]));
+}
+
+#[test]
+fn keeps_numbering_after_groups() {
+  let mut lines = lines("1\n\n2\n3\n");
+  let first = lines.by_ref().take(2).collect::<aoc::Result<Vec<_>>>();
+  assert_eq!(first, Ok(vec![Line::new(1, "1"), Line::new(2, "")]));
+  let rest = lines.groups().collect::<aoc::Result<Vec<_>>>();
+  assert_eq!(rest, Ok(vec![vec![Line::new(3, "2"), Line::new(4, "3")]]));
+}
@@ -0,0 +1,82 @@
+//! `aoc::Options::parse` on the arguments after the days.
+
+use std::path::{Path, PathBuf};
+
+use aoc::{Options, Part, Source};
+
+fn parse(args: &str) -> Result<Options, String> {
+  return Options::parse(args.split_whitespace().map(String::from));
+}
+
+#[test]
+fn defaults_to_both_datasets_and_parts() {
+  let options = parse("").unwrap();
+  assert_eq!(
+    options.sources,
+    [
+      Source::Dataset("small".to_string()),
+      Source::Dataset("input".to_string())
+    ]
+  );
+  assert!(options.runs(Part::One) && options.runs(Part::Two));
+  assert!(!options.picked_sources);
+  assert!(options.jobs >= 1);
+  assert_eq!(options.baseline_path(), Path::new("./data/baseline.tsv"));
+}
+
+#[test]
+fn picks_datasets_parts_and_files() {
+  let options = parse("input -p 2 --data /tmp/aoc").unwrap();
+  assert_eq!(options.sources, [Source::Dataset("input".to_string())]);
+  assert!(options.picked_sources);
+  assert!(!options.runs(Part::One) && options.runs(Part::Two));
+  assert_eq!(
+    options.sources[0].path(&options.data, "day7"),
+    "/tmp/aoc/day7/input.txt"
+  );
+
+  let options = parse("--input /tmp/other.txt").unwrap();
+  assert_eq!(
+    options.sources,
+    [Source::File(PathBuf::from("/tmp/other.txt"))]
+  );
+  assert_eq!(
+    options.sources[0].expected_path(&options.data, "day7"),
+    Some(PathBuf::from("/tmp/other.expected"))
+  );
+
+  let options = parse("-i -").unwrap();
+  assert_eq!(options.sources, [Source::Stdin]);
+  assert_eq!(
+    options.sources[0].expected_path(&options.data, "day7"),
+    None
+  );
+}
+
+#[test]
+fn rejects_invalid_combinations_and_values() {
+  let error = |args: &str| parse(args).unwrap_err();
+  assert_eq!(
+    error("small --input /tmp/other.txt"),
+    "`--input` can't be combined with datasets"
+  );
+  assert_eq!(error("-j 0"), "`-j` must be at least 1");
+  assert_eq!(error("--iterations 0"), "`--iterations` must be at least 1");
+  assert_eq!(error("-j two"), "invalid value `two` for `-j`");
+  assert_eq!(error("--part 3"), "invalid part `3`, expected 1 or 2");
+  assert_eq!(error("--part"), "missing value for `--part`");
+  assert_eq!(error("--verbose"), "unknown option `--verbose`");
+  for threshold in ["-5", "NaN", "inf"] {
+    assert_eq!(
+      error(&format!("--threshold {threshold}")),
+      format!("invalid value `{threshold}` for `--threshold`")
+    );
+  }
+}
+
+#[test]
+#[cfg(not(feature = "alloc-stats"))]
+fn caps_jobs_at_the_cores() {
+  let cores = std::thread::available_parallelism().unwrap().get();
+  assert_eq!(parse("-j 100000").unwrap().jobs, cores);
+}
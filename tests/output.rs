@@ -0,0 +1,52 @@
+//! The records of `--format csv` read back by `aoc::from_csv`.
+
+use aoc::{Memory, Record};
+
+fn record(phase: &'static str, answer: Option<&str>, error: Option<&str>) -> Record {
+  return Record {
+    day: "day10".to_string(),
+    dataset: "small".to_string(),
+    phase,
+    answer: answer.map(String::from),
+    error: error.map(String::from),
+    nanos: 1234,
+    memory: None,
+  };
+}
+
+#[test]
+fn reads_back_what_it_writes() {
+  let mut read = record("read", None, None);
+  read.memory = Some(Memory {
+    allocations: 3,
+    bytes: 4096,
+    peak: 1024,
+  });
+  let records = [
+    read,
+    record("part1", Some("a, \"quoted\"\nanswer"), None),
+    record("part2", None, Some("line 1, column 2: unexpected `,`")),
+  ];
+
+  let csv = aoc::to_csv(&records);
+  let back = aoc::from_csv(&csv).unwrap();
+  assert_eq!(back.len(), records.len());
+  for (a, b) in records.iter().zip(&back) {
+    assert_eq!((&a.day, &a.dataset, a.phase), (&b.day, &b.dataset, b.phase));
+    assert_eq!(
+      (&a.answer, &a.error, a.nanos),
+      (&b.answer, &b.error, b.nanos)
+    );
+    assert_eq!(
+      a.memory.map(|m| (m.allocations, m.bytes, m.peak)),
+      b.memory.map(|m| (m.allocations, m.bytes, m.peak))
+    );
+  }
+}
+
+#[test]
+fn rejects_other_csv() {
+  assert!(aoc::from_csv("day,answer\nday1,3\n").is_err());
+  let csv = aoc::to_csv(&[record("part3", Some("1"), None)]);
+  assert!(aoc::from_csv(&csv).is_err());
+}
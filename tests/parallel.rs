@@ -0,0 +1,43 @@
+//! `aoc::run_ordered` with more tasks than threads.
+
+#[test]
+fn reports_in_order_and_keeps_going_after_a_panic() {
+  let tasks = (0..20).collect::<Vec<u64>>();
+  for jobs in [1, 4] {
+    let mut reported = vec![];
+    aoc::run_ordered(
+      jobs,
+      &tasks,
+      |&n| {
+        if n == 7 {
+          panic!("no {n}");
+        }
+        return n * n;
+      },
+      |&n, result| reported.push((n, result)),
+    )
+    .unwrap();
+
+    assert_eq!(reported.len(), tasks.len());
+    for (n, result) in reported.iter().enumerate() {
+      match n {
+        7 => assert_eq!(result, &(7, Err("no 7".to_string()))),
+        n => assert_eq!(result, &(n as u64, Ok(n as u64 * n as u64))),
+      }
+    }
+  }
+}
+
+#[test]
+fn leaves_every_core_to_rayon_in_the_tasks() {
+  let cores = rayon::current_num_threads();
+  let mut threads = vec![];
+  aoc::run_ordered(
+    2,
+    &[(), ()],
+    |_| rayon::current_num_threads(),
+    |_, result| threads.push(result.unwrap()),
+  )
+  .unwrap();
+  assert_eq!(threads, [cores, cores]);
+}
@@ -0,0 +1,141 @@
+//! `aoc::scaffold` on a stand-in repository with a few days registered.
+
+use std::{
+  env, fs, io,
+  path::{Path, PathBuf},
+};
+
+const REGISTRY: &str = "\
+use crate::Solution;
+
+pub mod day1;
+pub mod day10;
+pub mod day3;
+
+pub struct Day;
+
+pub const DAYS: &[Day] = &[
+  Day::new(1, day1::solution),
+  Day::new(3, day3::solution),
+  Day::new(10, day10::solution),
+];
+";
+
+/// A repository named after the test, with `registry` as its
+/// `src/days/mod.rs`. Returns its root.
+fn repository(name: &str, registry: &str) -> PathBuf {
+  let root = env::temp_dir().join(format!("aoc-scaffold-{name}-{}", std::process::id()));
+  let _ = fs::remove_dir_all(&root);
+  fs::create_dir_all(root.join("src/days")).unwrap();
+  fs::write(root.join("src/days/mod.rs"), registry).unwrap();
+  return root;
+}
+
+fn registry(root: &Path) -> String {
+  return fs::read_to_string(root.join("src/days/mod.rs")).unwrap();
+}
+
+#[test]
+fn registers_a_day_in_order() {
+  let root = repository("order", REGISTRY);
+  let written = aoc::scaffold(&root, &root.join("data"), 2).unwrap();
+  assert_eq!(
+    written,
+    [
+      root.join("src/days/day2.rs"),
+      root.join("src/days/mod.rs"),
+      root.join("data/day2/small.txt"),
+      root.join("data/day2/input.txt"),
+    ]
+  );
+
+  let registry = registry(&root);
+  let modules = registry
+    .lines()
+    .filter(|line| line.starts_with("pub mod "))
+    .collect::<Vec<_>>();
+  assert_eq!(
+    modules,
+    [
+      "pub mod day1;",
+      "pub mod day10;",
+      "pub mod day2;",
+      "pub mod day3;"
+    ]
+  );
+  let entries = registry
+    .lines()
+    .filter(|line| line.contains("Day::new"))
+    .map(str::trim)
+    .collect::<Vec<_>>();
+  assert_eq!(
+    entries,
+    [
+      "Day::new(1, day1::solution),",
+      "Day::new(2, day2::solution),",
+      "Day::new(3, day3::solution),",
+      "Day::new(10, day10::solution),",
+    ]
+  );
+  assert!(fs::read_to_string(root.join("src/days/day2.rs"))
+    .unwrap()
+    .contains("struct Day2;"));
+  fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn refuses_a_registered_day() {
+  let root = repository("registered", REGISTRY);
+  let error = aoc::scaffold(&root, &root.join("data"), 3).unwrap_err();
+  assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+  assert_eq!(registry(&root), REGISTRY);
+  assert!(!root.join("src/days/day3.rs").exists());
+  fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn refuses_a_registry_without_days() {
+  for (name, registry) in [
+    ("modules", "pub const DAYS: &[Day] = &[\n];\n"),
+    ("days", "pub mod day1;\n"),
+    ("end", "pub mod day1;\n\npub const DAYS: &[Day] = &[\n"),
+  ] {
+    let root = repository(name, registry);
+    let error = aoc::scaffold(&root, &root.join("data"), 2).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData, "{name}");
+    assert!(!root.join("src/days/day2.rs").exists());
+    fs::remove_dir_all(&root).unwrap();
+  }
+
+  let root = env::temp_dir().join("aoc-scaffold-nowhere");
+  let error = aoc::scaffold(&root, &root.join("data"), 2).unwrap_err();
+  assert_eq!(error.kind(), io::ErrorKind::NotFound);
+}
+
+#[test]
+fn keeps_existing_files() {
+  let root = repository("existing", REGISTRY);
+  fs::write(root.join("src/days/day2.rs"), "// mine\n").unwrap();
+  let error = aoc::scaffold(&root, &root.join("data"), 2).unwrap_err();
+  assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+  assert_eq!(
+    fs::read_to_string(root.join("src/days/day2.rs")).unwrap(),
+    "// mine\n"
+  );
+  assert_eq!(registry(&root), REGISTRY);
+  fs::remove_file(root.join("src/days/day2.rs")).unwrap();
+
+  // Datasets that are already there, like a fetched input, stay as they are.
+  fs::create_dir_all(root.join("data/day2")).unwrap();
+  fs::write(root.join("data/day2/input.txt"), "1\n").unwrap();
+  let written = aoc::scaffold(&root, &root.join("data"), 2).unwrap();
+  assert!(!written.contains(&root.join("data/day2/input.txt")));
+  assert_eq!(
+    fs::read_to_string(root.join("data/day2/input.txt")).unwrap(),
+    "1\n"
+  );
+
+  let error = aoc::scaffold(&root, &root.join("data"), 26).unwrap_err();
+  assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+  fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,55 @@
+//! `aoc::Line::scan` extracting fields and pointing at deviations from templates.
+
+use aoc::Line;
+
+const SENSOR: &str = "Sensor at x={}, y={}: closest beacon is at x={}, y={}";
+
+fn column(result: aoc::Result<impl Sized>) -> (usize, String) {
+  let error = result.err().unwrap();
+  return (error.column, error.message);
+}
+
+#[test]
+fn extracts_typed_fields() {
+  let line = Line::new(1, "Sensor at x=2, y=-18: closest beacon is at x=-2, y=15");
+  let fields = aoc::scan!(line, SENSOR, i64, i64, i64, i64);
+  assert_eq!(fields, Ok((2, -18, -2, 15)));
+
+  let line = Line::new(1, "  Operation:  new = old *   19");
+  let fields = line.scan(" Operation: new = old {} {}");
+  assert_eq!(fields, Ok(["*", "19"]));
+  let line = Line::new(1, "tunnel leads to valve GG");
+  assert_eq!(
+    line.scan("tunnel{} lead{} to valve{} {}"),
+    Ok(["", "s", "", "GG"])
+  );
+}
+
+#[test]
+fn points_at_the_deviation() {
+  let line = Line::new(1, "Sensor at x=2, z=18: closest beacon is at x=-2, y=15");
+  let expected = (16, "expected `y=`".to_string());
+  assert_eq!(column(line.scan::<4>(SENSOR)), expected);
+
+  let line = Line::new(1, "Sensor at x=2, y=18");
+  let expected = (20, "expected `: closest beacon is at x=`".to_string());
+  assert_eq!(column(line.scan::<4>(SENSOR)), expected);
+
+  let line = Line::new(1, "Sensor at x=2, y=18: closest beacon is at x=-2, y=1x");
+  let expected = (
+    51,
+    "invalid `1x`: invalid digit found in string".to_string(),
+  );
+  assert_eq!(
+    column(aoc::scan!(line, SENSOR, i64, i64, i64, i64)),
+    expected
+  );
+
+  let template = "move {} from {} to {}.";
+  let line = Line::new(1, "move 1 from 2 to 3 now");
+  let expected = (23, "expected `.`".to_string());
+  assert_eq!(column(line.scan::<3>(template)), expected);
+  let line = Line::new(1, "move 1 from 2 to 3. now");
+  let expected = (20, "unexpected ` now`".to_string());
+  assert_eq!(column(line.scan::<3>(template)), expected);
+}
@@ -0,0 +1,78 @@
+//! `aoc::bfs`, `aoc::dijkstra`, `aoc::astar` and `aoc::bfs_distances` on small graphs.
+
+use aoc::{astar, bfs, bfs_distances, dijkstra, Route};
+
+/// `a -1- b -1- c -7- d` with a shortcut `a -4- d` and an unreachable `e`.
+fn roads(node: &char) -> Vec<(char, u32)> {
+  return match node {
+    'a' => vec![('b', 1), ('d', 4)],
+    'b' => vec![('a', 1), ('c', 1)],
+    'c' => vec![('b', 1), ('d', 7)],
+    'd' => vec![('a', 4), ('c', 7)],
+    _ => vec![],
+  };
+}
+
+fn unweighted(node: &char) -> Vec<char> {
+  return roads(node).into_iter().map(|(next, _)| next).collect();
+}
+
+#[test]
+fn finds_the_cheapest_route() {
+  let route = dijkstra(['c'], roads, |&n| n == 'd');
+  let expected = Route {
+    cost: 6,
+    nodes: vec!['c', 'b', 'a', 'd'],
+  };
+  assert_eq!(route, Some(expected));
+  assert_eq!(
+    bfs(['c'], unweighted, |&n| n == 'd').unwrap().nodes,
+    ['c', 'd']
+  );
+}
+
+#[test]
+fn starts_from_any_start_and_stops_at_any_goal() {
+  let route = dijkstra(['d', 'b'], roads, |&n| n == 'a' || n == 'c').unwrap();
+  assert_eq!((route.cost, route.nodes.len()), (1, 2));
+  let route = bfs(['e', 'a'], unweighted, |&n| n == 'a').unwrap();
+  assert_eq!(route.nodes, ['a']);
+  assert_eq!(dijkstra(['e'], roads, |&n| n == 'a'), None);
+}
+
+#[test]
+fn astar_walks_a_grid() {
+  // An open 10x10 grid with a wall at x = 5 except for y = 9.
+  let wall = |x: i32, y: i32| x == 5 && y != 9;
+  let neighbours = |&(x, y): &(i32, i32)| {
+    [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+      .into_iter()
+      .filter(|&(x, y)| (0..10).contains(&x) && (0..10).contains(&y) && !wall(x, y))
+      .map(|p| (p, 1))
+  };
+  let goal = (9, 0);
+  let route = astar(
+    [(0, 0)],
+    neighbours,
+    |&(x, y)| (goal.0 - x).abs() + (goal.1 - y).abs(),
+    |&p| p == goal,
+  )
+  .unwrap();
+  assert_eq!(route.cost, 27);
+  assert_eq!(route.nodes.len(), 28);
+  assert!(route.nodes.iter().all(|&(x, y)| !wall(x, y)));
+  assert_eq!(
+    dijkstra([(0, 0)], neighbours, |&p| p == goal).unwrap().cost,
+    27
+  );
+}
+
+#[test]
+fn measures_everything_reachable() {
+  let distances = bfs_distances(['a'], unweighted);
+  assert_eq!(distances.len(), 4);
+  assert_eq!(
+    (distances[&'a'], distances[&'c'], distances[&'d']),
+    (0, 2, 1)
+  );
+}